@@ -1,18 +1,24 @@
 #![no_std]
 
 use shared::{
-    constants::{MIN_VALIDATORS, RESUME_TIME_DELAY, UPGRADE_TIME_LOCK_SECS},
+    constants::{
+        JUROR_SERVICE_SCORE, JUROR_SLASH_SCORE, MAX_BATCH_SIZE, MAX_RESUBMISSIONS, MAX_URI_LEN,
+        MILESTONE_VOTE_PERIOD, MIN_REVEAL_QUORUM, MIN_VALIDATORS, RESUME_TIME_DELAY,
+    },
     errors::Error,
     events::*,
+    math::pro_rata,
     types::{
-        Amount, Dispute, DisputeResolution, DisputeStatus, EscrowInfo, Hash, JurorInfo, Milestone,
-        MilestoneStatus, PauseState, PendingUpgrade, VoteCommitment,
+        Amount, BadgeType, BatchResult, Dispute, DisputeConfig, DisputeResolution, DisputeStats,
+        DisputeStatus, EscrowInfo, Hash, JurorInfo, Milestone, MilestoneStatus, PauseState,
+        PendingUpgrade, ReputationProfile, VoteCommitment, VoteOutcome,
     },
+    upgradeable::{self, Upgradeable},
     MAX_APPROVAL_THRESHOLD, MIN_APPROVAL_THRESHOLD,
 };
 use soroban_sdk::{
     contract, contractimpl, contracttype, token::TokenClient, Address, BytesN, Env, IntoVal,
-    Symbol, Vec,
+    String, Symbol, Vec,
 };
 
 // Interface for ProfitDistribution
@@ -23,9 +29,33 @@ pub trait ProfitDistributionTrait {
         project_id: u64,
         depositor: Address,
         amount: i128,
+    ) -> Result<i128, shared::errors::Error>;
+}
+
+// Interface for Reputation
+#[soroban_sdk::contractclient(name = "ReputationClient")]
+pub trait ReputationTrait {
+    fn get_profile(env: Env, user: Address) -> Result<ReputationProfile, shared::errors::Error>;
+
+    fn grant_badge(
+        env: Env,
+        granter: Address,
+        user: Address,
+        badge: BadgeType,
     ) -> Result<(), shared::errors::Error>;
+
+    fn grant_score_updates(
+        env: Env,
+        granter: Address,
+        updates: Vec<(Address, i128)>,
+    ) -> Result<u32, shared::errors::Error>;
+
+    fn is_banned(env: Env, user: Address) -> bool;
 }
 
+/// Basis points representing approval of the full milestone amount
+const FULL_APPROVAL_BPS: u32 = 10_000;
+
 mod storage;
 mod validation;
 mod yield_router;
@@ -58,6 +88,33 @@ pub struct EmergencyWithdrawState {
     pub executed_at: u64,
 }
 
+/// Overview of an escrow's funds and milestones, in the escrow's default token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSummary {
+    pub total_deposited: Amount,
+    pub released: Amount,
+    pub available: Amount,
+    pub pending_count: u32,
+    pub submitted_count: u32,
+    pub approved_count: u32,
+    pub rejected_count: u32,
+    pub cancelled_count: u32,
+    /// Unreleased amount of milestones still pending or submitted
+    pub pending_amount: Amount,
+}
+
+/// Live progress of a milestone's validator vote
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteStatus {
+    pub approval_count: u32,
+    pub rejection_count: u32,
+    /// Approvals needed to pass, from the threshold and the milestone amount
+    pub required_approvals: u32,
+    pub total_validators: u32,
+}
+
 #[contract]
 pub struct EscrowContract;
 
@@ -70,6 +127,29 @@ impl EscrowContract {
         }
         admin.require_auth();
         set_admin(&env, &admin);
+        set_version(&env, 1);
+        Ok(())
+    }
+
+    /// Hand platform admin rights to a new address
+    ///
+    /// Any upgrade scheduled by the outgoing admin is cancelled.
+    ///
+    /// # Arguments
+    /// * `admin` - Current platform admin
+    /// * `new_admin` - Address receiving admin rights
+    pub fn transfer_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &new_admin);
+        upgradeable::invalidate_on_admin_change::<Self>(&env, &admin);
+
+        env.events()
+            .publish((ADMIN_TRANSFERRED,), (admin, new_admin));
         Ok(())
     }
 
@@ -79,7 +159,13 @@ impl EscrowContract {
     /// * `project_id` - Unique project identifier
     /// * `creator` - Address of the project creator
     /// * `token` - Token address for the escrow
-    /// * `validators` - List of validator addresses for milestone approval
+    /// * `validators` - Distinct validator addresses for milestone approval
+    /// * `deposit_deadline` - Last timestamp at which deposits are accepted, 0 for no deadline
+    /// * `quorum` - Votes a milestone needs before it can finalize, 0 for none;
+    ///   at most the number of validators
+    /// * `min_dispute_contribution` - Contribution a backer needs to open a dispute,
+    ///   0 for the default `MIN_CONTRIBUTION`
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         env: Env,
         project_id: u64,
@@ -88,13 +174,26 @@ impl EscrowContract {
         validators: Vec<Address>,
         approval_threshold: u32,
         management_fee_bps: u32,
+        deposit_deadline: u64,
+        quorum: u32,
+        min_dispute_contribution: Amount,
     ) -> Result<(), Error> {
         creator.require_auth();
 
         // Validate inputs
-        if validators.len() < MIN_VALIDATORS {
+        if validators.len() < MIN_VALIDATORS || quorum > validators.len() {
+            return Err(Error::InvInput);
+        }
+        validation::validate_unique_validators(&validators)?;
+
+        if min_dispute_contribution < 0 {
             return Err(Error::InvInput);
         }
+        let min_dispute_contribution = if min_dispute_contribution == 0 {
+            shared::constants::MIN_CONTRIBUTION
+        } else {
+            min_dispute_contribution
+        };
 
         if management_fee_bps > 10000 {
             return Err(Error::InvInput);
@@ -109,6 +208,9 @@ impl EscrowContract {
             return Err(Error::InvInput);
         }
 
+        // Optional spam protection: creator must meet the configured reputation
+        require_creator_reputation(&env, &creator)?;
+
         // Create escrow info
         let escrow = EscrowInfo {
             project_id,
@@ -119,6 +221,9 @@ impl EscrowContract {
             validators,
             approval_threshold,
             management_fee_bps,
+            deposit_deadline,
+            quorum,
+            min_dispute_contribution,
         };
 
         // Store escrow
@@ -134,6 +239,49 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Initialize an escrow and fund its first deposit in the same call
+    ///
+    /// Runs `initialize`, records the deposit and transfers `amount` of `token`
+    /// from the creator to the escrow, so the escrow never exists without the
+    /// tokens it reports; any failure reverts all three.
+    ///
+    /// # Arguments
+    /// * `amount` - Initial deposit, must be positive
+    ///
+    /// The remaining arguments are as for `initialize`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_with_deposit(
+        env: Env,
+        project_id: u64,
+        creator: Address,
+        token: Address,
+        validators: Vec<Address>,
+        approval_threshold: u32,
+        management_fee_bps: u32,
+        deposit_deadline: u64,
+        quorum: u32,
+        min_dispute_contribution: Amount,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        Self::initialize(
+            env.clone(),
+            project_id,
+            creator.clone(),
+            token.clone(),
+            validators,
+            approval_threshold,
+            management_fee_bps,
+            deposit_deadline,
+            quorum,
+            min_dispute_contribution,
+        )?;
+        Self::deposit(env.clone(), project_id, amount)?;
+
+        // Creator auth was already required by `initialize`
+        TokenClient::new(&env, &token).transfer(&creator, &env.current_contract_address(), &amount);
+        Ok(())
+    }
+
     fn calculate_required_signatures(milestone_amount: Amount, total_validators: u32) -> u32 {
         let base_required = if milestone_amount <= 1_000 {
             2
@@ -152,6 +300,15 @@ impl EscrowContract {
         }
     }
 
+    /// Approvals needed to pass a milestone: the stricter of the value-based and threshold rules
+    fn required_approvals(escrow: &EscrowInfo, milestone_amount: Amount) -> u32 {
+        let total_validators = escrow.validators.len();
+        let value_based_required =
+            Self::calculate_required_signatures(milestone_amount, total_validators);
+        let threshold_required = (total_validators * escrow.approval_threshold) / 10000;
+        core::cmp::max(value_based_required, threshold_required)
+    }
+
     /// Deposit funds into the escrow
     ///
     /// # Arguments
@@ -166,9 +323,10 @@ impl EscrowContract {
             return Err(Error::InvInput);
         }
 
-        if is_paused(&env) {
+        if is_paused_for(&env, project_id) {
             return Err(Error::Paused);
         }
+        require_deposit_window(&env, &escrow)?;
 
         // Update total deposited
         escrow.total_deposited = escrow
@@ -185,62 +343,201 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Deposit funds in a specific token into the escrow
+    ///
+    /// Deposits in the escrow's default token are the same as `deposit`; other
+    /// tokens are tracked separately and fund milestones paid in that token.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `token` - Asset being deposited
+    /// * `amount` - Amount to deposit (note: actual token transfer would be handled separately)
+    pub fn deposit_token(
+        env: Env,
+        project_id: u64,
+        token: Address,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        if token == escrow.token {
+            return Self::deposit(env, project_id, amount);
+        }
+
+        if amount <= 0 {
+            return Err(Error::InvInput);
+        }
+
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+        require_deposit_window(&env, &escrow)?;
+
+        let deposited = get_token_deposited(&env, project_id, &token)
+            .checked_add(amount)
+            .ok_or(Error::InvInput)?;
+        set_token_deposited(&env, project_id, &token, deposited);
+
+        env.events()
+            .publish((FUNDS_LOCKED,), (project_id, amount, token));
+
+        Ok(())
+    }
+
     /// Create a new milestone
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
     /// * `description_hash` - Hash of the milestone description
     /// * `amount` - Amount to be released when milestone is approved
+    ///
+    /// # Errors
+    /// * `Paused` - The contract or project is paused
+    /// * `InvInput` - Amount is below the minimum milestone amount
     pub fn create_milestone(
         env: Env,
         project_id: u64,
         description_hash: Hash,
         amount: Amount,
+    ) -> Result<(), Error> {
+        let token = get_escrow(&env, project_id)?.token;
+        Self::create_milestone_in_token(env, project_id, description_hash, amount, token)
+    }
+
+    /// Create a new milestone paid in a specific token
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `description_hash` - Hash of the milestone description
+    /// * `amount` - Amount to be released when milestone is approved
+    /// * `token` - Asset the milestone is paid in, overriding the escrow default
+    ///
+    /// # Errors
+    /// * `Paused` - The contract or project is paused
+    /// * `InvInput` - Amount is below the minimum milestone amount
+    /// * `EscrowInsuf` - Milestones in `token` would exceed the deposits in `token`
+    pub fn create_milestone_in_token(
+        env: Env,
+        project_id: u64,
+        description_hash: Hash,
+        amount: Amount,
+        token: Address,
     ) -> Result<(), Error> {
         // Get escrow to verify it exists and get creator
         let escrow = get_escrow(&env, project_id)?;
         escrow.creator.require_auth();
 
-        // Validate amount
-        if amount <= 0 {
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+
+        Self::add_milestone(&env, &escrow, description_hash, amount, token)
+    }
+
+    /// Create several milestones in the escrow's default token, in order
+    ///
+    /// Each milestone is checked against the deposits left after the ones before
+    /// it; milestones that fail validation are skipped and reported as failed by
+    /// their position in `milestones`.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestones` - Pairs of description hash and amount
+    ///
+    /// # Errors
+    /// * `Paused` - The contract or project is paused
+    /// * `InvInput` - More than MAX_BATCH_SIZE milestones
+    pub fn batch_create_milestones(
+        env: Env,
+        project_id: u64,
+        milestones: Vec<(Hash, Amount)>,
+    ) -> Result<BatchResult, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+        if milestones.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvInput);
+        }
+
+        let mut result = BatchResult {
+            succeeded: 0,
+            failed: Vec::new(&env),
+        };
+        for (index, (description_hash, amount)) in milestones.iter().enumerate() {
+            match Self::add_milestone(
+                &env,
+                &escrow,
+                description_hash,
+                amount,
+                escrow.token.clone(),
+            ) {
+                Ok(()) => result.succeeded += 1,
+                Err(_) => result.failed.push_back(index as u64),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Validate and store a milestone; the caller has checked the creator's auth
+    fn add_milestone(
+        env: &Env,
+        escrow: &EscrowInfo,
+        description_hash: Hash,
+        amount: Amount,
+        token: Address,
+    ) -> Result<(), Error> {
+        let project_id = escrow.project_id;
+
+        // Validate amount, keeping out spam milestones too small to be worth a vote
+        if amount <= 0 || amount < get_min_milestone_amount(env) {
             return Err(Error::InvInput);
         }
 
-        // Validate that total milestone amounts don't exceed escrow total
-        let total_milestones = get_total_milestone_amount(&env, project_id)?;
+        // Validate that milestone amounts don't exceed the deposits in the same token
+        let total_milestones = get_token_milestone_amount(env, project_id, &token)?;
         let new_total = total_milestones
             .checked_add(amount)
             .ok_or(Error::InvInput)?;
 
-        if new_total > escrow.total_deposited {
+        let (deposited, _) = token_totals(env, escrow, &token);
+        if new_total > deposited {
             return Err(Error::EscrowInsuf);
         }
 
-        if is_paused(&env) {
-            return Err(Error::Paused);
+        // Get next milestone ID, bounded so milestone scans stay predictable
+        let milestone_id = get_milestone_counter(env, project_id)?;
+        if milestone_id >= get_milestone_limit(env) {
+            return Err(Error::MstoneLimit);
         }
-
-        // Get next milestone ID
-        let milestone_id = get_milestone_counter(&env, project_id)?;
         let next_id = milestone_id.checked_add(1).ok_or(Error::InvInput)?;
 
         // Create milestone (with empty proof hash)
-        let empty_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let empty_hash = BytesN::from_array(env, &[0u8; 32]);
         let milestone = Milestone {
             id: milestone_id,
             project_id,
             description_hash: description_hash.clone(),
             amount,
+            released_so_far: 0,
+            token,
             status: MilestoneStatus::Pending,
             proof_hash: empty_hash,
+            proof_uri: String::from_str(env, ""),
             approval_count: 0,
             rejection_count: 0,
+            resubmission_count: 0,
             created_at: env.ledger().timestamp(),
+            submitted_at: 0,
+            vote_deadline: 0,
+            rejected_at: 0,
         };
 
         // Store milestone
-        set_milestone(&env, project_id, milestone_id, &milestone);
-        set_milestone_counter(&env, project_id, next_id);
+        set_milestone(env, project_id, milestone_id, &milestone);
+        set_milestone_counter(env, project_id, next_id);
 
         // Emit event
         env.events().publish(
@@ -257,12 +554,17 @@ impl EscrowContract {
     /// * `project_id` - Project identifier
     /// * `milestone_id` - Milestone identifier
     /// * `proof_hash` - Hash of the milestone proof
+    /// * `proof_uri` - Where validators can fetch the proof, at most MAX_URI_LEN bytes
     pub fn submit_milestone(
         env: Env,
         project_id: u64,
         milestone_id: u64,
         proof_hash: Hash,
+        proof_uri: String,
     ) -> Result<(), Error> {
+        if proof_uri.len() > MAX_URI_LEN {
+            return Err(Error::InvInput);
+        }
         // Get escrow to verify it exists and get creator
         let escrow = get_escrow(&env, project_id)?;
         escrow.creator.require_auth();
@@ -275,147 +577,442 @@ impl EscrowContract {
             return Err(Error::MstoneInv);
         }
 
-        if is_paused(&env) {
+        if is_paused_for(&env, project_id) {
             return Err(Error::Paused);
         }
 
-        // Update milestone
-        milestone.status = MilestoneStatus::Submitted;
-        milestone.proof_hash = proof_hash.clone();
+        Self::open_milestone_vote(&env, &mut milestone, proof_hash, proof_uri);
+        Ok(())
+    }
 
-        // Store updated milestone
-        set_milestone(&env, project_id, milestone_id, &milestone);
+    /// Abandon a milestone that has not been decided, freeing its allocation
+    ///
+    /// The milestone becomes `Cancelled` and votes cast on it are discarded. Its
+    /// amount no longer counts against deposits, so new milestones or a surplus
+    /// withdrawal can use it.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    ///
+    /// # Events
+    /// * `MILESTONE_ABANDONED` - Emitted with the freed amount
+    ///
+    /// # Errors
+    /// * `MstoneInv` - Milestone is not pending or submitted, or has an open dispute
+    pub fn abandon_milestone(env: Env, project_id: u64, milestone_id: u64) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+        if milestone.status != MilestoneStatus::Pending
+            && milestone.status != MilestoneStatus::Submitted
+        {
+            return Err(Error::MstoneInv);
+        }
+        if has_open_milestone_dispute(&env, project_id, milestone_id) {
+            return Err(Error::MstoneInv);
+        }
 
-        // Reset vote counts for new submission
-        set_milestone_votes(&env, project_id, milestone_id, 0, 0);
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
 
-        // Clear previous validators who voted
+        milestone.status = MilestoneStatus::Cancelled;
+        set_milestone(&env, project_id, milestone_id, &milestone);
         clear_milestone_voters(&env, project_id, milestone_id);
 
-        // Emit event
-        env.events().publish(
-            (MILESTONE_SUBMITTED,),
-            (project_id, milestone_id, proof_hash),
-        );
+        let freed = milestone.amount - milestone.released_so_far;
+        env.events()
+            .publish((MILESTONE_ABANDONED,), (project_id, milestone_id, freed));
 
         Ok(())
     }
 
-    /// Vote on a milestone (approve or reject)
+    /// Put a rejected milestone up for a fresh vote with new proof
+    ///
+    /// The milestone keeps its ID and history; votes and voters from the previous
+    /// round are cleared and `resubmission_count` is incremented.
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
     /// * `milestone_id` - Milestone identifier
-    /// * `voter` - Address of the voter
-    /// * `approve` - True to approve, false to reject
-    pub fn vote_milestone(
+    /// * `proof_hash` - Hash of the new milestone proof
+    /// * `proof_uri` - Where validators can fetch the proof, at most MAX_URI_LEN bytes
+    ///
+    /// # Errors
+    /// * `MstoneInv` - Milestone is not rejected
+    /// * `MstoneLimit` - Milestone was already resubmitted MAX_RESUBMISSIONS times
+    pub fn resubmit_milestone(
         env: Env,
         project_id: u64,
         milestone_id: u64,
-        voter: Address,
-        approve: bool,
+        proof_hash: Hash,
+        proof_uri: String,
     ) -> Result<(), Error> {
-        voter.require_auth();
-
-        // Get escrow
-        let mut escrow = get_escrow(&env, project_id)?;
-        validation::validate_validator(&escrow, &voter)?;
+        if proof_uri.len() > MAX_URI_LEN {
+            return Err(Error::InvInput);
+        }
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
 
-        // Get milestone
         let mut milestone = get_milestone(&env, project_id, milestone_id)?;
-
-        // Validate milestone status
-        if milestone.status != MilestoneStatus::Submitted {
+        if milestone.status != MilestoneStatus::Rejected {
             return Err(Error::MstoneInv);
         }
-
-        // Check if validator already voted
-        if has_validator_voted(&env, project_id, milestone_id, &voter)? {
-            return Err(Error::AlreadyVoted);
-        }
-
-        // Update vote counts
-        if approve {
-            milestone.approval_count = milestone
-                .approval_count
-                .checked_add(1)
-                .ok_or(Error::InvInput)?;
-        } else {
-            milestone.rejection_count = milestone
-                .rejection_count
-                .checked_add(1)
-                .ok_or(Error::InvInput)?;
+        if milestone.resubmission_count >= MAX_RESUBMISSIONS {
+            return Err(Error::MstoneLimit);
         }
 
-        if is_paused(&env) {
+        if is_paused_for(&env, project_id) {
             return Err(Error::Paused);
         }
 
-        // Record that this validator voted
-        set_validator_vote(&env, project_id, milestone_id, &voter)?;
+        milestone.resubmission_count += 1;
+        Self::open_milestone_vote(&env, &mut milestone, proof_hash, proof_uri);
+        Ok(())
+    }
 
-        // Check if milestone is approved or rejected
-        let _total_votes = milestone.approval_count as u32 + milestone.rejection_count as u32;
-        let value_based_required = Self::calculate_required_signatures(milestone.amount, escrow.validators.len() as u32);
-        let threshold_required = (escrow.validators.len() as u32 * escrow.approval_threshold) / 10000;
-        let required_approvals = if value_based_required > threshold_required {
-            value_based_required
-        } else {
-            threshold_required
-        };
+    /// Mark a milestone submitted with its proof and start a clean vote
+    fn open_milestone_vote(
+        env: &Env,
+        milestone: &mut Milestone,
+        proof_hash: Hash,
+        proof_uri: String,
+    ) {
+        let (project_id, milestone_id) = (milestone.project_id, milestone.id);
 
-        // Check for majority approval
-        if milestone.approval_count as u32 >= required_approvals {
-            milestone.status = MilestoneStatus::Approved;
+        // Update milestone, resetting vote counts for the new submission
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.proof_hash = proof_hash.clone();
+        milestone.proof_uri = proof_uri;
+        milestone.approval_count = 0;
+        milestone.rejection_count = 0;
+        milestone.submitted_at = env.ledger().timestamp();
+        milestone.vote_deadline = milestone.submitted_at + MILESTONE_VOTE_PERIOD;
 
-            // Release funds
-            release_milestone_funds(&env, &mut escrow, &milestone)?;
+        // Store updated milestone
+        set_milestone(env, project_id, milestone_id, milestone);
 
-            // Perform token transfer to creator
-            let token_client = TokenClient::new(&env, &escrow.token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &escrow.creator,
-                &milestone.amount,
-            );
+        // Clear previous validators who voted
+        clear_milestone_voters(env, project_id, milestone_id);
 
-            // Store updated escrow
-            set_escrow(&env, project_id, &escrow);
+        // Emit event
+        env.events().publish(
+            (MILESTONE_SUBMITTED,),
+            (project_id, milestone_id, proof_hash),
+        );
+    }
 
-            // Store updated milestone
-            set_milestone(&env, project_id, milestone_id, &milestone);
+    /// Vote on a milestone (approve or reject)
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `voter` - Address of the voter
+    /// * `approve` - True to approve, false to reject
+    pub fn vote_milestone(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        voter: Address,
+        approve: bool,
+    ) -> Result<VoteOutcome, Error> {
+        let approve_bps = if approve { FULL_APPROVAL_BPS } else { 0 };
+        Self::cast_milestone_vote(env, project_id, milestone_id, voter, approve_bps, false)
+    }
 
-            // Emit approval event
-            env.events().publish(
-                (MILESTONE_APPROVED,),
-                (project_id, milestone_id, milestone.approval_count),
-            );
+    /// Vote on a milestone and record the hash of the validator's reasoning
+    ///
+    /// Reasons given with a rejection are returned by `get_rejection_reasons`,
+    /// giving jurors context if the milestone is disputed.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `voter` - Address of the voter
+    /// * `approve` - True to approve, false to reject
+    /// * `reason_hash` - Hash of the off-chain reasoning
+    pub fn vote_milestone_with_reason(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        voter: Address,
+        approve: bool,
+        reason_hash: Hash,
+    ) -> Result<VoteOutcome, Error> {
+        let outcome = Self::vote_milestone(
+            env.clone(),
+            project_id,
+            milestone_id,
+            voter.clone(),
+            approve,
+        )?;
+        set_vote_reason(&env, project_id, milestone_id, &voter, &reason_hash);
+        Ok(outcome)
+    }
 
-            // Emit fund release event
-            env.events().publish(
-                (FUNDS_RELEASED,),
-                (project_id, milestone_id, milestone.amount),
-            );
-        } else if milestone.rejection_count as u32
-            > escrow.validators.len() as u32 - required_approvals
+    /// Change a validator's earlier vote while the milestone is still being voted on
+    ///
+    /// The previous choice is withdrawn from the tally and the new one counted, after
+    /// which the milestone is finalized if either threshold is now met.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `voter` - Address of the validator who already voted
+    /// * `approve` - True to approve, false to reject
+    ///
+    /// # Errors
+    /// * `NotFound` - The validator has not voted on this milestone
+    /// * `AlreadyVoted` - The new choice matches the recorded vote
+    pub fn change_vote(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        voter: Address,
+        approve: bool,
+    ) -> Result<VoteOutcome, Error> {
+        let approve_bps = if approve { FULL_APPROVAL_BPS } else { 0 };
+        Self::cast_milestone_vote(env, project_id, milestone_id, voter, approve_bps, true)
+    }
+
+    /// Vote to approve a milestone at a reduced percentage
+    ///
+    /// Counts as an approval. Once the approval threshold is met, the median of
+    /// the approved percentages (full approvals count as 100%) is released.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `voter` - Address of the validator voting
+    /// * `approve_bps` - Approved share of the milestone amount in basis points (1..=10000)
+    pub fn vote_milestone_partial(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        voter: Address,
+        approve_bps: u32,
+    ) -> Result<VoteOutcome, Error> {
+        if approve_bps == 0 || approve_bps > FULL_APPROVAL_BPS {
+            return Err(Error::InvInput);
+        }
+        Self::cast_milestone_vote(env, project_id, milestone_id, voter, approve_bps, false)
+    }
+
+    /// Record a validator vote; `approve_bps` of 0 is a rejection
+    ///
+    /// With `replace` set, the validator's previous vote is withdrawn first.
+    fn cast_milestone_vote(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        voter: Address,
+        approve_bps: u32,
+        replace: bool,
+    ) -> Result<VoteOutcome, Error> {
+        set_lock(&env)?;
+        voter.require_auth();
+
+        // Get escrow
+        let mut escrow = get_escrow(&env, project_id)?;
+        validation::validate_validator(&escrow, &voter)?;
+
+        // Get milestone
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        // Validate milestone status
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(Error::MstoneInv);
+        }
+        if env.ledger().timestamp() > milestone.vote_deadline {
+            return Err(Error::VoteNA);
+        }
+
+        let mut approved_bps = get_milestone_approval_bps(&env, project_id, milestone_id);
+
+        if replace {
+            // Withdraw the previous choice before counting the new one
+            let previous = get_validator_vote(&env, project_id, milestone_id, &voter)
+                .ok_or(Error::NotFound)?;
+            if previous == approve_bps {
+                return Err(Error::AlreadyVoted);
+            }
+            remove_vote_reason(&env, project_id, milestone_id, &voter);
+            if previous > 0 {
+                milestone.approval_count -= 1;
+                if let Some(index) = approved_bps.first_index_of(previous) {
+                    approved_bps.remove(index);
+                }
+            } else {
+                milestone.rejection_count -= 1;
+            }
+        } else if has_validator_voted(&env, project_id, milestone_id, &voter)? {
+            // Check if validator already voted
+            return Err(Error::AlreadyVoted);
+        }
+
+        // Update vote counts
+        if approve_bps > 0 {
+            milestone.approval_count = milestone
+                .approval_count
+                .checked_add(1)
+                .ok_or(Error::InvInput)?;
+            approved_bps.push_back(approve_bps);
+        } else {
+            milestone.rejection_count = milestone
+                .rejection_count
+                .checked_add(1)
+                .ok_or(Error::InvInput)?;
+        }
+
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+
+        // Record that this validator voted
+        set_validator_vote(&env, project_id, milestone_id, &voter, approve_bps)?;
+        set_milestone_approval_bps(&env, project_id, milestone_id, &approved_bps);
+
+        // Check if milestone is approved or rejected
+        let required_approvals = Self::required_approvals(&escrow, milestone.amount);
+
+        // Nothing is final until enough validators have taken part
+        let quorum_met = milestone.approval_count + milestone.rejection_count >= escrow.quorum;
+
+        // Check for majority approval
+        let outcome = if quorum_met && milestone.approval_count >= required_approvals {
+            Self::approve_milestone(&env, &mut escrow, &mut milestone, &approved_bps)?;
+            VoteOutcome::Approved
+        } else if quorum_met
+            && milestone.rejection_count > escrow.validators.len() - required_approvals
         {
             // Majority has rejected
-            milestone.status = MilestoneStatus::Rejected;
-            set_milestone(&env, project_id, milestone_id, &milestone);
-
-            // Emit rejection event
-            env.events().publish(
-                (MILESTONE_REJECTED,),
-                (project_id, milestone_id, milestone.rejection_count),
-            );
+            Self::reject_milestone(&env, &mut milestone);
+            VoteOutcome::Rejected
         } else {
             // Store updated milestone (vote recorded, but not yet finalized)
             set_milestone(&env, project_id, milestone_id, &milestone);
+            VoteOutcome::Recorded
+        };
+
+        clear_lock(&env);
+        Ok(outcome)
+    }
+
+    /// Settle a milestone whose vote period ended without a decision
+    ///
+    /// Callable by anyone once `vote_deadline` has passed. Approves and releases
+    /// if the recorded approvals meet the threshold and the votes meet the
+    /// quorum, and rejects otherwise, so every submitted milestone reaches a
+    /// final state.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    ///
+    /// # Errors
+    /// * `MstoneInv` - Milestone is not awaiting votes
+    /// * `VoteNA` - Vote period has not ended
+    /// * `Paused` - The contract or the project is paused
+    pub fn finalize_milestone_vote(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<VoteOutcome, Error> {
+        set_lock(&env)?;
+        let mut escrow = get_escrow(&env, project_id)?;
+        let mut milestone = get_milestone(&env, project_id, milestone_id)?;
+
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(Error::MstoneInv);
+        }
+        if env.ledger().timestamp() <= milestone.vote_deadline {
+            return Err(Error::VoteNA);
+        }
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+
+        let required_approvals = Self::required_approvals(&escrow, milestone.amount);
+        let quorum_met = milestone.approval_count + milestone.rejection_count >= escrow.quorum;
+        let outcome = if quorum_met
+            && milestone.approval_count > 0
+            && milestone.approval_count >= required_approvals
+        {
+            let approved_bps = get_milestone_approval_bps(&env, project_id, milestone_id);
+            Self::approve_milestone(&env, &mut escrow, &mut milestone, &approved_bps)?;
+            VoteOutcome::Approved
+        } else {
+            Self::reject_milestone(&env, &mut milestone);
+            VoteOutcome::Rejected
+        };
+
+        clear_lock(&env);
+        Ok(outcome)
+    }
+
+    /// Approve a milestone and release the median of the approved percentages to the creator
+    fn approve_milestone(
+        env: &Env,
+        escrow: &mut EscrowInfo,
+        milestone: &mut Milestone,
+        approved_bps: &Vec<u32>,
+    ) -> Result<(), Error> {
+        let (project_id, milestone_id) = (milestone.project_id, milestone.id);
+        milestone.status = MilestoneStatus::Approved;
+
+        // Release the agreed share of the milestone amount
+        let agreed_amount =
+            (milestone.amount * median_bps(approved_bps) as i128) / FULL_APPROVAL_BPS as i128;
+        let release_amount = release_milestone_funds(env, escrow, milestone, agreed_amount)?;
+
+        // Perform token transfer to creator
+        if release_amount > 0 {
+            let token_client = TokenClient::new(env, &milestone.token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.creator,
+                &release_amount,
+            );
         }
 
+        // Store updated escrow
+        set_escrow(env, project_id, escrow);
+
+        // Store updated milestone
+        set_milestone(env, project_id, milestone_id, milestone);
+
+        // Emit approval event
+        env.events().publish(
+            (MILESTONE_APPROVED,),
+            (project_id, milestone_id, milestone.approval_count),
+        );
+
+        // Emit fund release event
+        env.events().publish(
+            (FUNDS_RELEASED,),
+            (project_id, milestone_id, release_amount),
+        );
+
+        award_milestone_badge(env, &escrow.creator);
         Ok(())
     }
 
+    /// Mark a milestone rejected
+    fn reject_milestone(env: &Env, milestone: &mut Milestone) {
+        let (project_id, milestone_id) = (milestone.project_id, milestone.id);
+        milestone.status = MilestoneStatus::Rejected;
+        milestone.rejected_at = env.ledger().timestamp();
+        set_milestone(env, project_id, milestone_id, milestone);
+
+        // Emit rejection event
+        env.events().publish(
+            (MILESTONE_REJECTED,),
+            (project_id, milestone_id, milestone.rejection_count),
+        );
+    }
+
     /// Get escrow information
     ///
     /// # Arguments
@@ -441,47 +1038,525 @@ impl EscrowContract {
         get_total_milestone_amount(&env, project_id)
     }
 
-    /// Get remaining available balance in escrow
+    /// Get how a validator voted on each milestone of a project
+    ///
+    /// Returns `(milestone_id, approve)` pairs for each milestone's current vote
+    /// round; partial approvals count as approve. Resubmitting a milestone drops
+    /// its entry.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    pub fn get_validator_votes(env: Env, project_id: u64, validator: Address) -> Vec<(u64, bool)> {
+        get_validator_history(&env, project_id, &validator)
+    }
+
+    /// Get how a milestone's current vote round stands
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_vote_status(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<VoteStatus, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        let milestone = get_milestone(&env, project_id, milestone_id)?;
+        Ok(VoteStatus {
+            approval_count: milestone.approval_count,
+            rejection_count: milestone.rejection_count,
+            required_approvals: Self::required_approvals(&escrow, milestone.amount),
+            total_validators: escrow.validators.len(),
+        })
+    }
+
+    /// Check whether a validator has voted in a milestone's current vote round
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    /// * `validator` - Validator address
+    pub fn has_voted(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+        validator: Address,
+    ) -> Result<bool, Error> {
+        has_validator_voted(&env, project_id, milestone_id, &validator)
+    }
+
+    /// List the reason hashes given by validators currently rejecting a milestone
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_rejection_reasons(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<Vec<(Address, Hash)>, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        get_milestone(&env, project_id, milestone_id)?;
+
+        let mut reasons = Vec::new(&env);
+        for validator in escrow.validators.iter() {
+            if get_validator_vote(&env, project_id, milestone_id, &validator) != Some(0) {
+                continue;
+            }
+            if let Some(reason_hash) = get_vote_reason(&env, project_id, milestone_id, &validator) {
+                reasons.push_back((validator, reason_hash));
+            }
+        }
+        Ok(reasons)
+    }
+
+    /// List the submitted milestones of a project a validator has not voted on yet
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address
+    ///
+    /// # Errors
+    /// * `NotValidator` - Address is not on the project's validator panel
+    pub fn pending_votes_for(
+        env: Env,
+        project_id: u64,
+        validator: Address,
+    ) -> Result<Vec<u64>, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        validation::validate_validator(&escrow, &validator)?;
+
+        let mut pending = Vec::new(&env);
+        let counter = get_milestone_counter(&env, project_id)?;
+        for milestone_id in 0..counter {
+            let Ok(milestone) = get_milestone(&env, project_id, milestone_id) else {
+                continue;
+            };
+            if milestone.status == MilestoneStatus::Submitted
+                && !has_validator_voted(&env, project_id, milestone_id, &validator)?
+            {
+                pending.push_back(milestone_id);
+            }
+        }
+        Ok(pending)
+    }
+
+    /// Get the number of milestones created for a project
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_milestone_count(env: Env, project_id: u64) -> Result<u64, Error> {
+        get_milestone_counter(&env, project_id)
+    }
+
+    /// Get how much of a milestone has been paid out so far
     ///
     /// # Arguments
     /// * `project_id` - Project identifier
-    pub fn get_available_balance(env: Env, project_id: u64) -> Result<Amount, Error> {
+    /// * `milestone_id` - Milestone identifier
+    pub fn get_milestone_released(
+        env: Env,
+        project_id: u64,
+        milestone_id: u64,
+    ) -> Result<Amount, Error> {
+        Ok(get_milestone(&env, project_id, milestone_id)?.released_so_far)
+    }
+
+    /// Get remaining available balance in escrow for one token
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `token` - Asset to report, the escrow default or a milestone token
+    pub fn get_available_balance(
+        env: Env,
+        project_id: u64,
+        token: Address,
+    ) -> Result<Amount, Error> {
         let escrow = get_escrow(&env, project_id)?;
-        Ok(escrow.total_deposited - escrow.released_amount)
+        let (deposited, released) = token_totals(&env, &escrow, &token);
+        Ok(deposited - released)
+    }
+
+    /// Get an overview of an escrow's funds and milestone states in one call
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    pub fn get_escrow_summary(env: Env, project_id: u64) -> Result<EscrowSummary, Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        let mut summary = EscrowSummary {
+            total_deposited: escrow.total_deposited,
+            released: escrow.released_amount,
+            available: escrow.total_deposited - escrow.released_amount,
+            pending_count: 0,
+            submitted_count: 0,
+            approved_count: 0,
+            rejected_count: 0,
+            cancelled_count: 0,
+            pending_amount: 0,
+        };
+
+        // The counter is capped by the deployment's milestone limit
+        let counter = get_milestone_counter(&env, project_id)?;
+        for milestone_id in 0..counter {
+            let Ok(milestone) = get_milestone(&env, project_id, milestone_id) else {
+                continue;
+            };
+            match milestone.status {
+                MilestoneStatus::Pending => summary.pending_count += 1,
+                MilestoneStatus::Submitted => summary.submitted_count += 1,
+                MilestoneStatus::Approved => summary.approved_count += 1,
+                MilestoneStatus::Rejected => summary.rejected_count += 1,
+                MilestoneStatus::Cancelled => summary.cancelled_count += 1,
+            }
+            if matches!(
+                milestone.status,
+                MilestoneStatus::Pending | MilestoneStatus::Submitted
+            ) {
+                summary.pending_amount = summary
+                    .pending_amount
+                    .checked_add(milestone.amount - milestone.released_so_far)
+                    .ok_or(Error::InvInput)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Return deposits no milestone can still claim to the creator
+    ///
+    /// Covers the escrow's default token only, and is allowed once every milestone paid
+    /// in it is approved, rejected or cancelled. The unreleased part of rejected milestones stays
+    /// reserved, since a dispute can still release it. The surplus is removed from
+    /// `total_deposited`, so it cannot be withdrawn twice.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    ///
+    /// # Events
+    /// * `SURPLUS_WITHDRAWN` - Emitted with the amount returned to the creator
+    ///
+    /// # Errors
+    /// * `MstoneInv` - A milestone is still pending or submitted
+    /// * `EscrowInsuf` - There is no surplus to withdraw
+    pub fn withdraw_surplus(env: Env, project_id: u64) -> Result<Amount, Error> {
+        set_lock(&env)?;
+        let mut escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        if is_paused_for(&env, project_id) {
+            return Err(Error::Paused);
+        }
+
+        let mut reserved: Amount = 0;
+        for milestone_id in 0..get_milestone_counter(&env, project_id)? {
+            let milestone = get_milestone(&env, project_id, milestone_id)?;
+            if milestone.token != escrow.token {
+                continue;
+            }
+            match milestone.status {
+                MilestoneStatus::Pending | MilestoneStatus::Submitted => {
+                    return Err(Error::MstoneInv);
+                }
+                MilestoneStatus::Rejected => {
+                    reserved += milestone.amount - milestone.released_so_far;
+                }
+                MilestoneStatus::Approved | MilestoneStatus::Cancelled => {}
+            }
+        }
+
+        let surplus = escrow.total_deposited - escrow.released_amount - reserved;
+        if surplus <= 0 {
+            return Err(Error::EscrowInsuf);
+        }
+
+        escrow.total_deposited -= surplus;
+        set_escrow(&env, project_id, &escrow);
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &escrow.creator, &surplus);
+
+        env.events()
+            .publish((SURPLUS_WITHDRAWN,), (project_id, escrow.creator, surplus));
+
+        clear_lock(&env);
+        Ok(surplus)
+    }
+
+    /// Appoint an operator who may manage this project's validators
+    ///
+    /// Replaces any previous operator. The platform admin keeps full access.
+    ///
+    /// # Arguments
+    /// * `project_id` - Project identifier
+    /// * `operator` - Address acting for the project
+    pub fn set_operator(env: Env, project_id: u64, operator: Address) -> Result<(), Error> {
+        let escrow = get_escrow(&env, project_id)?;
+        escrow.creator.require_auth();
+
+        set_project_operator(&env, project_id, &operator);
+
+        env.events()
+            .publish((OPERATOR_SET,), (project_id, operator));
+
+        Ok(())
+    }
+
+    /// Get the operator appointed for a project, if any
+    pub fn get_operator(env: Env, project_id: u64) -> Option<Address> {
+        get_project_operator(&env, project_id)
+    }
+
+    /// Update validators for an escrow
+    ///
+    /// Votes already cast on submitted milestones are discarded so the new set
+    /// votes from scratch.
+    ///
+    /// # Arguments
+    /// * `caller` - Platform admin or the project's operator
+    /// * `project_id` - Project identifier
+    /// * `new_validators` - New list of distinct validator addresses
+    pub fn update_validators(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        new_validators: Vec<Address>,
+    ) -> Result<(), Error> {
+        require_project_admin(&env, project_id, &caller)?;
+
+        // Validate new validators
+        if new_validators.len() < MIN_VALIDATORS {
+            return Err(Error::InvInput);
+        }
+        validation::validate_unique_validators(&new_validators)?;
+
+        // Get escrow
+        let mut escrow = get_escrow(&env, project_id)?;
+        if new_validators.len() < escrow.quorum {
+            return Err(Error::InvInput);
+        }
+
+        // Update validators
+        replace_validators(&env, project_id, &mut escrow, new_validators.clone())?;
+
+        // Emit event
+        env.events()
+            .publish((VALIDATORS_UPDATED,), (project_id, new_validators));
+
+        Ok(())
+    }
+
+    /// Replace the validator sets of several escrows at once (admin only)
+    ///
+    /// Projects that do not exist, or whose new set is below MIN_VALIDATORS or
+    /// the project's quorum or repeats an address, are skipped and reported as
+    /// failed. Votes already cast on submitted milestones of an updated project
+    /// are discarded so the new set votes from scratch.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    /// * `updates` - Pairs of project identifier and new validator list
+    ///
+    /// # Errors
+    /// * `InvInput` - More than MAX_BATCH_SIZE updates
+    pub fn batch_update_validators(
+        env: Env,
+        admin: Address,
+        updates: Vec<(u64, Vec<Address>)>,
+    ) -> Result<BatchResult, Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if updates.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvInput);
+        }
+
+        let mut result = BatchResult {
+            succeeded: 0,
+            failed: Vec::new(&env),
+        };
+        for (project_id, new_validators) in updates.iter() {
+            if new_validators.len() < MIN_VALIDATORS
+                || validation::validate_unique_validators(&new_validators).is_err()
+            {
+                result.failed.push_back(project_id);
+                continue;
+            }
+            let mut escrow = match get_escrow(&env, project_id) {
+                Ok(escrow) if new_validators.len() >= escrow.quorum => escrow,
+                _ => {
+                    result.failed.push_back(project_id);
+                    continue;
+                }
+            };
+
+            replace_validators(&env, project_id, &mut escrow, new_validators.clone())?;
+
+            env.events()
+                .publish((VALIDATORS_UPDATED,), (project_id, new_validators));
+            result.succeeded += 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Add a single validator to an escrow
+    ///
+    /// Votes already cast on submitted milestones are discarded so the new set
+    /// votes from scratch.
+    ///
+    /// # Arguments
+    /// * `caller` - Platform admin or the project's operator
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address to add
+    ///
+    /// # Errors
+    /// * `InvInput` - Address is already a validator
+    pub fn add_validator(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        validator: Address,
+    ) -> Result<(), Error> {
+        require_project_admin(&env, project_id, &caller)?;
+
+        let mut escrow = get_escrow(&env, project_id)?;
+        if validation::validate_validator(&escrow, &validator).is_ok() {
+            return Err(Error::InvInput);
+        }
+
+        let mut validators = escrow.validators.clone();
+        validators.push_back(validator.clone());
+        replace_validators(&env, project_id, &mut escrow, validators)?;
+
+        env.events()
+            .publish((VALIDATOR_ADDED,), (project_id, validator));
+
+        Ok(())
+    }
+
+    /// Remove a single validator from an escrow
+    ///
+    /// Votes already cast on submitted milestones are discarded, including the
+    /// removed validator's, so the new set votes from scratch.
+    ///
+    /// # Arguments
+    /// * `caller` - Platform admin or the project's operator
+    /// * `project_id` - Project identifier
+    /// * `validator` - Validator address to remove
+    ///
+    /// # Errors
+    /// * `NotValidator` - Address is not a validator
+    /// * `InvInput` - Removal would leave fewer than MIN_VALIDATORS, or fewer
+    ///   validators than the quorum
+    pub fn remove_validator(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        validator: Address,
+    ) -> Result<(), Error> {
+        require_project_admin(&env, project_id, &caller)?;
+
+        let mut escrow = get_escrow(&env, project_id)?;
+        let index = escrow
+            .validators
+            .first_index_of(validator.clone())
+            .ok_or(Error::NotValidator)?;
+
+        if escrow.validators.len() <= MIN_VALIDATORS || escrow.validators.len() <= escrow.quorum {
+            return Err(Error::InvInput);
+        }
+
+        let mut validators = escrow.validators.clone();
+        validators.remove(index);
+        replace_validators(&env, project_id, &mut escrow, validators)?;
+
+        env.events()
+            .publish((VALIDATOR_REMOVED,), (project_id, validator));
+
+        Ok(())
+    }
+
+    /// Configure the reputation contract used for badge awards. Admin only.
+    ///
+    /// Escrow must be an authorized granter on the reputation contract.
+    pub fn configure_reputation_contract(
+        env: Env,
+        admin: Address,
+        reputation_contract: Address,
+    ) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        set_reputation_contract(&env, &reputation_contract);
+        Ok(())
+    }
+
+    /// Configure the project-launch contract that reports backer contributions. Admin only.
+    ///
+    /// Consulted through `get_user_contribution` when a backer opens a dispute and
+    /// when jurors who back the disputed project are excluded from its panel.
+    pub fn configure_project_launch(
+        env: Env,
+        admin: Address,
+        project_launch: Address,
+    ) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        set_project_launch(&env, &project_launch);
+        Ok(())
+    }
+
+    /// Get the configured project-launch contract, if any
+    pub fn get_project_launch(env: Env) -> Option<Address> {
+        get_project_launch(&env)
     }
 
-    /// Update validators for an escrow
+    /// Enable the minimum-reputation gate for escrow creation. Admin only.
     ///
     /// # Arguments
-    /// * `project_id` - Project identifier
-    /// * `new_validators` - New list of validator addresses
-    pub fn update_validators(
+    /// * `reputation_contract` - Reputation contract queried via `get_profile`
+    /// * `min_score` - Minimum creator score (e.g. `MIN_CREATOR_REPUTATION`)
+    pub fn configure_reputation_gate(
         env: Env,
-        project_id: u64,
-        new_validators: Vec<Address>,
+        admin: Address,
+        reputation_contract: Address,
+        min_score: i128,
     ) -> Result<(), Error> {
-        // Get admin
-        let admin = get_admin(&env)?;
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
         admin.require_auth();
 
-        // Validate new validators
-        if new_validators.len() < MIN_VALIDATORS {
+        if min_score < 0 {
             return Err(Error::InvInput);
         }
 
-        // Get escrow
-        let mut escrow = get_escrow(&env, project_id)?;
-
-        // Update validators
-        escrow.validators = new_validators.clone();
-
-        // Store updated escrow
-        set_escrow(&env, project_id, &escrow);
+        set_reputation_contract(&env, &reputation_contract);
+        set_min_creator_reputation(&env, min_score);
+        Ok(())
+    }
 
-        // Emit event
-        env.events()
-            .publish((VALIDATORS_UPDATED,), (project_id, new_validators));
+    /// Disable the minimum-reputation gate. Admin only.
+    pub fn disable_reputation_gate(env: Env, admin: Address) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
 
+        clear_min_creator_reputation(&env);
         Ok(())
     }
 
@@ -570,6 +1645,201 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Override the dispute token for a single project
+    ///
+    /// Appeal fees for the project are charged in this token and paid out to
+    /// its majority jurors; juror stakes and slashing stay in the global token.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `project_id` - Project identifier
+    /// * `token` - Token address
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `NotFound` - Project does not exist
+    pub fn configure_project_dispute_token(
+        env: Env,
+        project_id: u64,
+        token: Address,
+    ) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        if !escrow_exists(&env, project_id) {
+            return Err(Error::NotFound);
+        }
+
+        set_project_dispute_token(&env, project_id, &token);
+        Ok(())
+    }
+
+    /// Remove a project's dispute token override, reverting to the global token
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `project_id` - Project identifier
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    pub fn clear_project_dispute_token(env: Env, project_id: u64) -> Result<(), Error> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+
+        storage::clear_project_dispute_token(&env, project_id);
+        Ok(())
+    }
+
+    /// Get the dispute token in effect for a project
+    pub fn get_project_dispute_token(env: Env, project_id: u64) -> Result<Address, Error> {
+        get_dispute_token_for(&env, project_id)
+    }
+
+    /// Set how long after the reveal period late reveals are still accepted
+    ///
+    /// Late reveals count towards the outcome but are not rewarded.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `admin` - Must be the platform admin
+    /// * `grace` - Grace in seconds, at most VOTING_REVEAL_PERIOD
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvInput` - Grace exceeds the reveal period
+    pub fn configure_reveal_grace(env: Env, admin: Address, grace: u64) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if grace > shared::constants::VOTING_REVEAL_PERIOD {
+            return Err(Error::InvInput);
+        }
+
+        set_reveal_grace(&env, grace);
+        Ok(())
+    }
+
+    /// Set how many milestones a single project may create
+    ///
+    /// Until configured, MAX_MILESTONES_PER_PROJECT applies. Projects already
+    /// past a lowered limit keep their milestones but cannot add more.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `admin` - Must be the platform admin
+    /// * `limit` - Maximum milestones per project
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvInput` - Limit is zero
+    pub fn configure_milestone_limit(env: Env, admin: Address, limit: u64) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if limit == 0 {
+            return Err(Error::InvInput);
+        }
+
+        set_milestone_limit(&env, limit);
+        Ok(())
+    }
+
+    /// Get the number of milestones a single project may create
+    pub fn get_milestone_limit(env: Env) -> u64 {
+        get_milestone_limit(&env)
+    }
+
+    /// Set the smallest amount a new milestone may carry (admin only)
+    ///
+    /// Until configured, MIN_MILESTONE_AMOUNT applies. Existing milestones are
+    /// unaffected.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `admin` - Must be the platform admin
+    /// * `amount` - Minimum milestone amount
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvInput` - Amount is not positive
+    pub fn configure_min_milestone_amount(
+        env: Env,
+        admin: Address,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvInput);
+        }
+
+        set_min_milestone_amount(&env, amount);
+        Ok(())
+    }
+
+    /// Get the smallest amount a new milestone may carry
+    pub fn get_min_milestone_amount(env: Env) -> Amount {
+        get_min_milestone_amount(&env)
+    }
+
+    /// Set the economic parameters of the dispute system
+    ///
+    /// Until configured, the shared constants (MIN_JUROR_STAKE, JURY_SIZE,
+    /// APPEAL_JURY_SIZE, JUROR_SLASH_BPS, APPEAL_FEE) apply.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `admin` - Must be the platform admin
+    /// * `config` - New dispute parameters
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvInput` - Non-positive stake, even or out-of-order jury sizes,
+    ///   slash or winner share above 100%, negative appeal fee, or bond outside `0..=min_juror_stake`
+    pub fn configure_dispute_params(
+        env: Env,
+        admin: Address,
+        config: DisputeConfig,
+    ) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if config.min_juror_stake <= 0
+            || config.jury_size.is_multiple_of(2)
+            || config.appeal_jury_size.is_multiple_of(2)
+            || config.appeal_jury_size <= config.jury_size
+            || config.appeal_jury_size > MAX_BATCH_SIZE
+            || config.slash_bps > 10000
+            || config.slash_to_winner_bps > 10000
+            || config.appeal_fee < 0
+            || config.juror_bond < 0
+            || config.juror_bond > config.min_juror_stake
+        {
+            return Err(Error::InvInput);
+        }
+
+        set_dispute_config(&env, &config);
+        Ok(())
+    }
+
+    /// Get the dispute parameters in effect
+    pub fn get_dispute_params(env: Env) -> DisputeConfig {
+        get_dispute_config(&env)
+    }
+
     /// Register to be a juror
     ///
     /// # Arguments
@@ -581,9 +1851,11 @@ impl EscrowContract {
     /// * `InsufficientJurorStake` - Stake is below minimum
     /// * `AlreadyRegisteredAsJuror` - Juror is already registered
     pub fn register_as_juror(env: Env, juror: Address, stake_amount: Amount) -> Result<(), Error> {
+        set_lock(&env)?;
         juror.require_auth();
+        require_not_banned(&env, &juror)?;
 
-        if stake_amount < shared::constants::MIN_JUROR_STAKE {
+        if stake_amount < get_dispute_config(&env).min_juror_stake {
             return Err(Error::JurorStakeL);
         }
 
@@ -602,9 +1874,11 @@ impl EscrowContract {
         let juror_info = JurorInfo {
             address: juror.clone(),
             staked_amount: stake_amount,
+            rewards_accrued: 0,
             active_disputes: 0,
             successful_votes: 0,
             missed_votes: 0,
+            total_slashed: 0,
         };
         set_juror(&env, &juror, &juror_info);
 
@@ -613,6 +1887,7 @@ impl EscrowContract {
         active_jurors.push_back(juror.clone());
         set_active_jurors(&env, &active_jurors);
 
+        clear_lock(&env);
         Ok(())
     }
 
@@ -667,60 +1942,229 @@ impl EscrowContract {
             return Err(Error::AlreadyVoted);
         }
 
-        state.approvals.push_back(validator.clone());
-        state.status = EmergencyWithdrawStatus::Pending;
-        let approval_count = state.approvals.len() as u32;
+        state.approvals.push_back(validator.clone());
+        state.status = EmergencyWithdrawStatus::Pending;
+        let approval_count = state.approvals.len() as u32;
+
+        set_emergency_withdraw_state(&env, project_id, &state);
+        env.events().publish(
+            (EMERGENCY_WITHDRAW_APPROVED,),
+            (project_id, validator, approval_count),
+        );
+
+        Ok(approval_count)
+    }
+
+    /// Deregister as a juror
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `juror` - Address of the juror
+    ///
+    /// # Errors
+    /// * `NotAJuror` - Caller is not a registered juror
+    /// * `JurorHasActiveDispute` - Juror is assigned to an active dispute
+    pub fn deregister_as_juror(env: Env, juror: Address) -> Result<(), Error> {
+        set_lock(&env)?;
+        juror.require_auth();
+
+        let juror_info = get_juror(&env, &juror)?;
+
+        if juror_info.active_disputes > 0 {
+            return Err(Error::JurorAct);
+        }
+
+        let token = get_juror_token(&env)?;
+        let token_client = TokenClient::new(&env, &token);
+
+        // Transfer stake and any unwithdrawn rewards back to juror
+        token_client.transfer(
+            &env.current_contract_address(),
+            &juror,
+            &(juror_info.staked_amount + juror_info.rewards_accrued),
+        );
+
+        // Remove from storage
+        remove_juror(&env, &juror);
+
+        // Remove from active jurors list
+        let mut active_jurors = get_active_jurors(&env);
+        if let Some(index) = active_jurors.first_index_of(juror.clone()) {
+            active_jurors.remove(index);
+            set_active_jurors(&env, &active_jurors);
+        }
+
+        clear_lock(&env);
+        Ok(())
+    }
+
+    /// Return part of a juror's stake while keeping them registered
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `juror` - Address of the juror
+    /// * `amount` - Stake to return
+    ///
+    /// # Events
+    /// * `STAKE_REDUCED` - Emitted with the juror, the amount and the remaining stake
+    ///
+    /// # Errors
+    /// * `NotAJuror` - Caller is not a registered juror
+    /// * `InvalidInput` - Amount is not positive
+    /// * `JurorHasActiveDispute` - Juror is assigned to an active dispute
+    /// * `InsufficientJurorStake` - Remaining stake would fall below the minimum
+    pub fn reduce_stake(env: Env, juror: Address, amount: Amount) -> Result<(), Error> {
+        set_lock(&env)?;
+        juror.require_auth();
+
+        let mut juror_info = get_juror(&env, &juror)?;
+        if amount <= 0 {
+            return Err(Error::InvInput);
+        }
+        if juror_info.active_disputes > 0 {
+            return Err(Error::JurorAct);
+        }
+        let remaining = juror_info.staked_amount - amount;
+        if remaining < get_dispute_config(&env).min_juror_stake {
+            return Err(Error::JurorStakeL);
+        }
+        juror_info.staked_amount = remaining;
+        set_juror(&env, &juror, &juror_info);
+
+        let token = get_juror_token(&env)?;
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &juror, &amount);
+
+        env.events()
+            .publish((STAKE_REDUCED,), (juror, amount, remaining));
+
+        clear_lock(&env);
+        Ok(())
+    }
+
+    /// Withdraw the rewards a juror has earned while staying registered
+    ///
+    /// The stake is left untouched, so the juror keeps serving on panels.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `juror` - Address of the juror
+    ///
+    /// # Returns
+    /// * `Amount` - Rewards paid out in the juror token
+    ///
+    /// # Events
+    /// * `JUROR_REWARDS_WITHDRAWN` - Emitted with the juror and the amount paid
+    ///
+    /// # Errors
+    /// * `NotAJuror` - Caller is not a registered juror
+    /// * `NoClaim` - No rewards are waiting to be withdrawn
+    pub fn withdraw_rewards(env: Env, juror: Address) -> Result<Amount, Error> {
+        set_lock(&env)?;
+        juror.require_auth();
+
+        let mut juror_info = get_juror(&env, &juror)?;
+        let rewards = juror_info.rewards_accrued;
+        if rewards <= 0 {
+            return Err(Error::NoClaim);
+        }
+        juror_info.rewards_accrued = 0;
+        set_juror(&env, &juror, &juror_info);
+
+        let token = get_juror_token(&env)?;
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &juror, &rewards);
 
-        set_emergency_withdraw_state(&env, project_id, &state);
-        env.events().publish(
-            (EMERGENCY_WITHDRAW_APPROVED,),
-            (project_id, validator, approval_count),
-        );
+        env.events()
+            .publish((JUROR_REWARDS_WITHDRAWN,), (juror, rewards));
 
-        Ok(approval_count)
+        clear_lock(&env);
+        Ok(rewards)
     }
 
-    /// Deregister as a juror
+    /// Approve a delegate to commit and reveal votes on the juror's behalf
+    ///
+    /// The juror's stake, rewards and slashing are unaffected; the delegate only
+    /// signs vote actions. Delegating to the juror's own address revokes the delegate.
     ///
     /// # Arguments
     /// * `env` - Execution environment
-    /// * `juror` - Address of the juror
+    /// * `juror` - Address of the registered juror
+    /// * `delegate` - Address allowed to vote for the juror
+    ///
+    /// # Events
+    /// * `DELEGATE_SET` - Emitted with the juror and delegate
     ///
     /// # Errors
     /// * `NotAJuror` - Caller is not a registered juror
-    /// * `JurorHasActiveDispute` - Juror is assigned to an active dispute
-    pub fn deregister_as_juror(env: Env, juror: Address) -> Result<(), Error> {
+    pub fn delegate_juror_vote(env: Env, juror: Address, delegate: Address) -> Result<(), Error> {
         juror.require_auth();
+        get_juror(&env, &juror)?;
 
-        let juror_info = get_juror(&env, &juror)?;
+        set_juror_delegate(&env, &juror, &delegate);
+        env.events().publish((DELEGATE_SET,), (juror, delegate));
 
-        if juror_info.active_disputes > 0 {
-            return Err(Error::JurorAct);
+        Ok(())
+    }
+
+    /// Pause a single project, leaving every other project running
+    ///
+    /// Deposits, milestone changes and validator votes on the project are halted
+    /// until `resume_project`. The global pause still applies on top of this.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    /// * `project_id` - Project to pause
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvStatus` - Project is already paused
+    pub fn pause_project(env: Env, admin: Address, project_id: u64) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
         }
+        admin.require_auth();
+        get_escrow(&env, project_id)?;
 
-        let token = get_juror_token(&env)?;
-        let token_client = TokenClient::new(&env, &token);
+        if is_project_paused(&env, project_id) {
+            return Err(Error::InvStatus);
+        }
+        set_project_paused(&env, project_id, true);
 
-        // Transfer stake back to juror
-        token_client.transfer(
-            &env.current_contract_address(),
-            &juror,
-            &juror_info.staked_amount,
-        );
+        env.events().publish((PROJECT_PAUSED,), (admin, project_id));
+        Ok(())
+    }
 
-        // Remove from storage
-        remove_juror(&env, &juror);
+    /// Lift a single project's pause; a global pause keeps it halted
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    /// * `project_id` - Project to resume
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not admin
+    /// * `InvStatus` - Project is not paused
+    pub fn resume_project(env: Env, admin: Address, project_id: u64) -> Result<(), Error> {
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
 
-        // Remove from active jurors list
-        let mut active_jurors = get_active_jurors(&env);
-        if let Some(index) = active_jurors.first_index_of(juror.clone()) {
-            active_jurors.remove(index);
-            set_active_jurors(&env, &active_jurors);
+        if !is_project_paused(&env, project_id) {
+            return Err(Error::InvStatus);
         }
+        set_project_paused(&env, project_id, false);
 
+        env.events()
+            .publish((PROJECT_RESUMED,), (admin, project_id));
         Ok(())
     }
 
+    /// Check whether a project is halted, by its own pause or the global one
+    pub fn is_project_paused(env: Env, project_id: u64) -> bool {
+        is_paused_for(&env, project_id)
+    }
+
     /// Resume the contract — only allowed after the time delay has passed
     ///
     /// # Arguments
@@ -765,6 +2209,7 @@ impl EscrowContract {
         project_id: u64,
         admin: Address,
     ) -> Result<Amount, Error> {
+        set_lock(&env)?;
         let stored_admin = get_admin(&env)?;
         if stored_admin != admin {
             return Err(Error::Unauthorized);
@@ -796,9 +2241,64 @@ impl EscrowContract {
             (project_id, admin, rescued_amount),
         );
 
+        clear_lock(&env);
         Ok(rescued_amount)
     }
 
+    /// Emergency admin withdrawal of escrowed funds while the contract is paused.
+    ///
+    /// Transfers up to the escrow's available balance to `to` and counts the
+    /// withdrawn amount as released so balance accounting stays consistent.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the platform admin
+    /// * `project_id` - Project identifier
+    /// * `to` - Recipient of the rescued funds
+    /// * `amount` - Requested amount, capped at the available balance
+    ///
+    /// # Returns
+    /// * `Amount` - The amount actually withdrawn
+    pub fn emergency_withdraw(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+        to: Address,
+        amount: Amount,
+    ) -> Result<Amount, Error> {
+        set_lock(&env)?;
+        let stored_admin = get_admin(&env)?;
+        if stored_admin != admin {
+            return Err(Error::Unauthorized);
+        }
+        if !is_paused(&env) {
+            return Err(Error::InvStatus);
+        }
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvInput);
+        }
+
+        let mut escrow = get_escrow(&env, project_id)?;
+        let available = escrow.total_deposited - escrow.released_amount;
+        let withdrawn = core::cmp::min(amount, available);
+        if withdrawn <= 0 {
+            return Err(Error::EscrowInsuf);
+        }
+
+        escrow.released_amount += withdrawn;
+        set_escrow(&env, project_id, &escrow);
+
+        let token_client = TokenClient::new(&env, &escrow.token);
+        token_client.transfer(&env.current_contract_address(), &to, &withdrawn);
+
+        env.events()
+            .publish((EMERGENCY_WITHDRAWAL,), (project_id, admin, to, withdrawn));
+
+        clear_lock(&env);
+        Ok(withdrawn)
+    }
+
     /// Inspect the current emergency rescue state for a project.
     pub fn get_emergency_withdraw_state(env: Env, project_id: u64) -> EmergencyWithdrawState {
         storage::get_emergency_withdraw_state(&env, project_id)
@@ -810,20 +2310,26 @@ impl EscrowContract {
     /// * `env` - Execution environment
     /// * `project_id` - Project identifier
     /// * `milestone_id` - Milestone identifier
-    /// * `initiator` - Address initiating the dispute
-    /// * `project_contract` - Contract address for project launch (to verify backers)
+    /// * `initiator` - Address initiating the dispute; a backer other than the creator
+    ///   is checked against the configured project-launch contract
+    ///
+    /// # Events
+    /// * `DISPUTE_STATUS_CHANGED` - Emitted with `Pending` as old and new status
     ///
     /// # Errors
-    /// * `MilestoneNotContested` - Milestone is not in a contested state
-    /// * `Unauthorized` - Initiator is not creator or valid backer
+    /// * `MilestoneNotContested` - Milestone is not in a contested state, or its
+    ///   `CONTEST_WINDOW_SECS` after submission, or after rejection if later, have passed
+    /// * `Unauthorized` - Initiator is neither the creator nor a backer who contributed
+    ///   at least the escrow's `min_dispute_contribution`; backers cannot open disputes
+    ///   while no project-launch contract is configured
     pub fn initiate_dispute(
         env: Env,
         project_id: u64,
         milestone_id: u64,
         initiator: Address,
-        project_contract: Address,
     ) -> Result<u64, Error> {
         initiator.require_auth();
+        require_not_banned(&env, &initiator)?;
 
         let escrow = get_escrow(&env, project_id)?;
         let milestone = get_milestone(&env, project_id, milestone_id)?;
@@ -833,18 +2339,16 @@ impl EscrowContract {
         {
             return Err(Error::MstoneContest);
         }
+        // A rejection restarts the window, so a late or automatic one stays contestable
+        let window_start = core::cmp::max(milestone.submitted_at, milestone.rejected_at);
+        if env.ledger().timestamp() > window_start + shared::constants::CONTEST_WINDOW_SECS {
+            return Err(Error::MstoneContest);
+        }
 
         // Verify initiator is creator or qualifying funder
         if initiator != escrow.creator {
-            let args: Vec<soroban_sdk::Val> =
-                soroban_sdk::vec![&env, project_id.into_val(&env), initiator.into_val(&env)];
-            let contribution: Amount = env.invoke_contract(
-                &project_contract,
-                &soroban_sdk::Symbol::new(&env, "get_user_contribution"),
-                args,
-            );
-
-            if contribution < shared::constants::MIN_CONTRIBUTION {
+            let contribution = backer_contribution(&env, project_id, &initiator).unwrap_or(0);
+            if contribution < escrow.min_dispute_contribution {
                 return Err(Error::Unauthorized);
             }
         }
@@ -860,25 +2364,39 @@ impl EscrowContract {
             resolution: DisputeResolution::NoRes,
             resolution_payload: 0,
             appeal_count: 0,
+            appellants: Vec::new(&env),
+            pending_compensation: 0,
         };
 
         set_dispute(&env, dispute_id, &dispute);
         set_next_dispute_id(&env, dispute_id + 1);
+        set_milestone_dispute(&env, project_id, milestone_id, dispute_id);
+        emit_dispute_status(
+            &env,
+            dispute_id,
+            DisputeStatus::Pending,
+            DisputeStatus::Pending,
+        );
 
         Ok(dispute_id)
     }
 
     /// Select jury for a dispute using verifiable on-chain randomness
     ///
+    /// Candidates whose contribution to the project exceeds `MAX_JUROR_CONTRIBUTION`
+    /// are skipped, as are the creator, the initiator and jurors of earlier rounds.
+    ///
     /// # Arguments
     /// * `env` - Execution environment
     /// * `dispute_id` - Dispute identifier
     ///
     /// # Events
     /// * `JURY_SELECTED` - Emitted when jury selection completes
+    /// * `DISPUTE_STATUS_CHANGED` - Emitted with the old and new status
     ///
     /// # Errors
-    /// * `InvalidInput` - Dispute state invalid or not enough jurors
+    /// * `InvalidInput` - Dispute is not awaiting a panel
+    /// * `InsufficientJurors` - Fewer active jurors than the panel size
     /// * `ConflictOfInterest` - Not enough eligible jurors after excluding conflicts
     pub fn select_jury(env: Env, dispute_id: u64) -> Result<(), Error> {
         let mut dispute = get_dispute(&env, dispute_id)?;
@@ -887,15 +2405,16 @@ impl EscrowContract {
             return Err(Error::InvInput);
         }
 
+        let config = get_dispute_config(&env);
         let jury_size = if dispute.status == DisputeStatus::Appealed {
-            shared::constants::APPEAL_JURY_SIZE
+            config.appeal_jury_size
         } else {
-            shared::constants::JURY_SIZE
+            config.jury_size
         };
 
         let active_jurors = get_active_jurors(&env);
         if active_jurors.len() < jury_size {
-            return Err(Error::InvInput); // Not enough jurors
+            return Err(Error::InsufficientJurors);
         }
 
         let mut selected_jurors = Vec::new(&env);
@@ -903,11 +2422,9 @@ impl EscrowContract {
 
         let escrow = get_escrow(&env, dispute.project_id)?;
 
-        let existing_jurors = if dispute.appeal_count > 0 {
-            get_juror_assignments(&env, dispute_id).unwrap_or(Vec::new(&env))
-        } else {
-            Vec::new(&env)
-        };
+        // Jurors from earlier rounds never sit again, so each appeal panel is fresh
+        // and its (dispute, juror) vote commitments cannot collide with earlier rounds.
+        let mut past_jurors = get_past_jurors(&env, dispute_id);
 
         let prng = env.prng();
 
@@ -915,10 +2432,11 @@ impl EscrowContract {
             let index = prng.gen_range::<u64>(0..available_jurors.len() as u64) as u32;
             let juror = available_jurors.get(index).unwrap();
 
-            // Conflict of interest exclusion: creator, initiator, and previous jurors
+            // Conflict of interest exclusion: creator, initiator, previous jurors and large backers
             if juror != escrow.creator
                 && juror != dispute.initiator
-                && !existing_jurors.contains(&juror)
+                && !past_jurors.contains(&juror)
+                && !is_significant_backer(&env, &dispute, &juror)
             {
                 selected_jurors.push_back(juror.clone());
             }
@@ -938,10 +2456,15 @@ impl EscrowContract {
         }
 
         set_juror_assignments(&env, dispute_id, &selected_jurors);
+        past_jurors.append(&selected_jurors);
+        set_past_jurors(&env, dispute_id, &past_jurors);
 
+        // Opens a fresh commit window (and the reveal window after it) for this panel
+        let old_status = dispute.status;
         dispute.status = DisputeStatus::Voting;
         dispute.created_at = env.ledger().timestamp();
         set_dispute(&env, dispute_id, &dispute);
+        emit_dispute_status(&env, dispute_id, old_status, dispute.status);
 
         env.events()
             .publish((JURY_SELECTED,), (dispute_id, selected_jurors));
@@ -949,33 +2472,86 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Get a dispute
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Result<Dispute, Error> {
+        get_dispute(&env, dispute_id)
+    }
+
+    /// Get the addresses that appealed a dispute, in filing order
+    pub fn get_dispute_appellants(env: Env, dispute_id: u64) -> Result<Vec<Address>, Error> {
+        Ok(get_dispute(&env, dispute_id)?.appellants)
+    }
+
     /// Get the assigned jurors for a dispute
     pub fn get_juror_assignments(env: Env, dispute_id: u64) -> Result<Vec<Address>, Error> {
         get_juror_assignments(&env, dispute_id)
     }
 
+    /// Get a registered juror's stake and voting record
+    pub fn get_juror(env: Env, juror: Address) -> Result<JurorInfo, Error> {
+        get_juror(&env, &juror)
+    }
+
+    /// Get the share of a juror's tallied votes that sided with the majority
+    ///
+    /// # Returns
+    /// * `u32` - Basis points of `successful / (successful + missed)`, 0 when
+    ///   the juror has no tallied votes or is not registered
+    pub fn get_juror_win_rate(env: Env, juror: Address) -> u32 {
+        match get_juror(&env, &juror) {
+            Ok(info) => win_rate_bps(info.successful_votes, info.missed_votes),
+            Err(_) => 0,
+        }
+    }
+
+    /// List every registered juror
+    pub fn list_active_jurors(env: Env) -> Vec<Address> {
+        get_active_jurors(&env)
+    }
+
+    /// Get aggregate metrics of the jury system
+    pub fn get_dispute_stats(env: Env) -> DisputeStats {
+        DisputeStats {
+            active_jurors: get_active_jurors(&env).len(),
+            total_staked: get_total_juror_stake(&env),
+            fee_pool: get_dispute_fee_pool(&env),
+            next_dispute_id: get_next_dispute_id(&env),
+        }
+    }
+
+    /// Get the dispute fee pool awaiting distribution to majority jurors
+    ///
+    /// Rewards already earned are held per juror; see `withdraw_rewards`.
+    pub fn get_fee_pool(env: Env) -> Amount {
+        get_dispute_fee_pool(&env)
+    }
+
     /// Commit a blinded vote for a dispute
     ///
     /// # Arguments
     /// * `env` - Execution environment
     /// * `dispute_id` - Dispute identifier
     /// * `juror` - Address of the juror
+    /// * `caller` - The juror, or the juror's approved delegate
     /// * `commitment_hash` - Blinded hash of vote + salt
     ///
     /// # Events
     /// * `VOTE_COMMITTED` - Emitted when vote is committed
     ///
     /// # Errors
+    /// * `Unauthorized` - Caller is neither the juror nor its delegate
     /// * `VotingPeriodNotActive` - Not in commit phase
     /// * `NotAJuror` - Juror is not on panel
     /// * `AlreadyVoted` - Vote already committed
+    /// * `JurorStakeL` - Stake cannot cover the per-dispute bond
     pub fn commit_vote(
         env: Env,
         dispute_id: u64,
         juror: Address,
+        caller: Address,
         commitment_hash: Hash,
     ) -> Result<(), Error> {
-        juror.require_auth();
+        require_juror_or_delegate(&env, &juror, &caller)?;
 
         // panic!("CV2");
         let dispute = get_dispute(&env, dispute_id)?;
@@ -999,11 +2575,24 @@ impl EscrowContract {
             return Err(Error::AlreadyVoted);
         }
 
+        // Lock the per-dispute bond out of the juror's stake
+        let bond = get_dispute_config(&env).juror_bond;
+        if bond > 0 {
+            let mut info = get_juror(&env, &juror)?;
+            if info.staked_amount < bond {
+                return Err(Error::JurorStakeL);
+            }
+            info.staked_amount -= bond;
+            set_juror(&env, &juror, &info);
+            set_juror_bond(&env, dispute_id, &juror, bond);
+        }
+
         let commitment = VoteCommitment {
             hash: commitment_hash.clone(),
             revealed: false,
             vote: DisputeResolution::NoRes,
             vote_payload: 0,
+            late: false,
         };
 
         set_dispute_vote(&env, dispute_id, &juror, &commitment);
@@ -1019,13 +2608,15 @@ impl EscrowContract {
     /// * `env` - Execution environment
     /// * `dispute_id` - Dispute identifier
     /// * `juror` - Address of the juror
-    /// * `vote` - Plaintext vote
+    /// * `caller` - The juror, or the juror's approved delegate
+    /// * `vote` - Plaintext vote; `Abstain` takes part without backing a resolution
     /// * `salt` - Byte array salt
     ///
     /// # Events
     /// * `VOTE_REVEALED` - Emitted when vote is correctly revealed
     ///
     /// # Errors
+    /// * `Unauthorized` - Caller is neither the juror nor its delegate
     /// * `RevealPeriodNotActive` - Not in reveal phase
     /// * `AlreadyVoted` - Vote already revealed
     /// * `InvalidVoteReveal` - Hash mismatch
@@ -1033,11 +2624,12 @@ impl EscrowContract {
         env: Env,
         dispute_id: u64,
         juror: Address,
+        caller: Address,
         vote: DisputeResolution,
         payload: u32,
         salt: soroban_sdk::Bytes,
     ) -> Result<(), Error> {
-        juror.require_auth();
+        require_juror_or_delegate(&env, &juror, &caller)?;
 
         let dispute = get_dispute(&env, dispute_id)?;
 
@@ -1045,7 +2637,7 @@ impl EscrowContract {
         let commit_end = dispute.created_at + shared::constants::VOTING_COMMIT_PERIOD;
         let reveal_end = commit_end + shared::constants::VOTING_REVEAL_PERIOD;
 
-        if current_time <= commit_end || current_time > reveal_end {
+        if current_time <= commit_end || current_time > reveal_end + get_reveal_grace(&env) {
             return Err(Error::RevealNA);
         }
 
@@ -1055,30 +2647,16 @@ impl EscrowContract {
             return Err(Error::AlreadyVoted);
         }
 
-        // Construct preimage: variant_index + (optional payload) + salt
-        let mut b = soroban_sdk::Bytes::new(&env);
-        match vote {
-            DisputeResolution::RelFunds => b.push_back(0),
-            DisputeResolution::RefBackers => b.push_back(1),
-            DisputeResolution::PartRel => {
-                b.push_back(2);
-                for byte in payload.to_be_bytes() {
-                    b.push_back(byte);
-                }
-            }
-            DisputeResolution::NoRes => return Err(Error::InvInput),
-        }
-        b.append(&salt);
-
-        let expected_hash = env.crypto().sha256(&b);
+        let expected_hash = commitment_hash(&env, vote, payload, &salt)?;
 
-        if commitment.hash.to_array() != expected_hash.to_array() {
+        if commitment.hash != expected_hash {
             return Err(Error::InvReveal);
         }
 
         commitment.revealed = true;
         commitment.vote = vote;
         commitment.vote_payload = payload;
+        commitment.late = current_time > reveal_end;
         set_dispute_vote(&env, dispute_id, &juror, &commitment);
 
         env.events().publish((VOTE_REVEALED,), (dispute_id, juror));
@@ -1086,21 +2664,208 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Compute the commitment hash for a vote, exactly as `reveal_vote` verifies it
+    ///
+    /// Lets jurors check their commitment off-chain before the reveal phase.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `vote` - Plaintext vote
+    /// * `payload` - Release percentage in basis points (only hashed for `PartRel`)
+    /// * `salt` - Byte array salt
+    ///
+    /// # Errors
+    /// * `InvalidInput` - `NoRes` is not a valid vote
+    pub fn compute_commitment(
+        env: Env,
+        vote: DisputeResolution,
+        payload: u32,
+        salt: soroban_sdk::Bytes,
+    ) -> Result<Hash, Error> {
+        commitment_hash(&env, vote, payload, &salt)
+    }
+
     /// Tally revealed votes, reward majority, slash minority/non-revealers
     ///
+    /// Only records the resolution; no escrow funds move until
+    /// `execute_resolution` is called, including after the final appeal.
+    ///
+    /// A tie between resolutions goes to the most conservative one: `RefBackers`,
+    /// then `PartRel`, then `RelFunds`.
+    ///
+    /// Revealed `Abstain` votes count towards the reveal quorum and are neither
+    /// slashed nor rewarded, but never decide the outcome; a panel that only
+    /// abstained is replaced like one that missed the quorum.
+    ///
+    /// A panel where fewer than `MIN_REVEAL_QUORUM` of the jurors revealed does not
+    /// resolve the dispute: its non-revealers are slashed and a fresh panel is
+    /// selected, or the dispute returns to `Pending` if none can be seated yet.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `dispute_id` - Dispute identifier
+    ///
+    /// # Events
+    /// * `DISPUTE_RESOLVED` - Emitted when outcome is tallied
+    /// * `DISPUTE_STATUS_CHANGED` - Emitted with the old and new status
+    /// * `JUROR_SLASHED` - Emitted for each slashed juror
+    ///
+    /// # Errors
+    /// * `VotingPeriodNotActive` - Reveal period not ended
+    pub fn tally_votes(env: Env, dispute_id: u64) -> Result<(), Error> {
+        set_lock(&env)?;
+        Self::tally(&env, dispute_id)?;
+        clear_lock(&env);
+        Ok(())
+    }
+
+    /// Resolve a dispute left in `Voting` after its reveal period has ended
+    ///
+    /// Callable by anyone so a dispute never depends on a particular caller to
+    /// progress. Runs the same tally as `tally_votes`: if fewer than
+    /// `MIN_REVEAL_QUORUM` of the panel revealed, non-revealers are slashed and a
+    /// fresh panel is selected instead.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `dispute_id` - Dispute identifier
+    ///
+    /// # Returns
+    /// * `DisputeResolution` - The resolution the dispute was settled with, `NoRes` if the panel was replaced
+    ///
+    /// # Errors
+    /// * `VotingPeriodNotActive` - Dispute not in voting, or reveal period (plus grace) not ended
+    pub fn force_resolve_stale_dispute(
+        env: Env,
+        dispute_id: u64,
+    ) -> Result<DisputeResolution, Error> {
+        set_lock(&env)?;
+        let resolution = Self::tally(&env, dispute_id)?;
+        clear_lock(&env);
+        Ok(resolution)
+    }
+
+    /// Execute resolution after appeal window closes
+    ///
+    /// This is the only step that enforces a resolution, so it must be called
+    /// once the window closes even when no appeal remains.
+    ///
+    /// Vote commitments and juror assignments of the dispute are removed once
+    /// it is final; the dispute record is kept for history. The winner's share of
+    /// stake slashed across all rounds is paid out here as well.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `dispute_id` - Dispute identifier
+    ///
+    /// # Events
+    /// * `APPEAL_RESOLVED` - Emitted when final enforcement is done
+    /// * `SLASH_COMPENSATED` - Emitted when a share of slashed stake is paid out
+    /// * `DISPUTE_STATUS_CHANGED` - Emitted with the old and new status
+    ///
+    /// # Errors
+    /// * `InvalidInput` - Dispute not resolved
+    /// * `AppealWindowClosed` - Appeal window still open
+    pub fn execute_resolution(env: Env, dispute_id: u64) -> Result<(), Error> {
+        set_lock(&env)?;
+        let mut dispute = get_dispute(&env, dispute_id)?;
+
+        if dispute.status != DisputeStatus::Resolved {
+            return Err(Error::InvInput);
+        }
+
+        let current_time = env.ledger().timestamp();
+        // Dispute resolution time-lock:
+        // - While appeals remain available, wait for the appeal window to elapse (and also satisfy the minimum lock).
+        // - Once max appeals are exhausted, enforcements still must wait for the resolution time-lock.
+        //
+        // `created_at` is reset in `tally_votes` and marks the start of these windows.
+        let required_delay = if dispute.appeal_count >= shared::constants::MAX_APPEALS as u32 {
+            shared::constants::RESOLUTION_TIME_LOCK
+        } else {
+            core::cmp::max(
+                shared::constants::RESOLUTION_TIME_LOCK,
+                shared::constants::APPEAL_WINDOW_PERIOD,
+            )
+        };
+
+        if current_time <= dispute.created_at + required_delay {
+            return Err(Error::AppealWinCl); // Reusing for window still open
+        }
+
+        dispute.status = DisputeStatus::FinalResolved;
+        let compensation = core::mem::take(&mut dispute.pending_compensation);
+        set_dispute(&env, dispute_id, &dispute);
+        emit_dispute_status(
+            &env,
+            dispute_id,
+            DisputeStatus::Resolved,
+            DisputeStatus::FinalResolved,
+        );
+        clear_dispute_votes(&env, dispute_id);
+
+        Self::enforce_resolution(&env, &dispute)?;
+        Self::compensate_winner(&env, &dispute, compensation)?;
+        env.events()
+            .publish((APPEAL_RESOLVED,), (dispute_id, dispute.resolution));
+
+        clear_lock(&env);
+        Ok(())
+    }
+
+    /// Remove leftover vote commitments and juror assignments of a final dispute
+    ///
+    /// `execute_resolution` already does this; the call covers disputes finalized
+    /// before that cleanup existed. Callable by anyone, and a no-op when there is
+    /// nothing left to remove.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment
+    /// * `dispute_id` - Dispute identifier
+    ///
+    /// # Errors
+    /// * `InvalidInput` - Dispute is not `FinalResolved`
+    pub fn cleanup_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
+        let dispute = get_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::FinalResolved {
+            return Err(Error::InvInput);
+        }
+        clear_dispute_votes(&env, dispute_id);
+        Ok(())
+    }
+
+    /// Preview what `execute_resolution` would do for a resolved dispute, without mutating state
+    ///
     /// # Arguments
     /// * `env` - Execution environment
     /// * `dispute_id` - Dispute identifier
     ///
-    /// # Events
-    /// * `DISPUTE_RESOLVED` - Emitted when outcome is tallied
-    /// * `JUROR_SLASHED` - Emitted for each slashed juror
-    /// * `APPEAL_RESOLVED` - Emitted if max appeals reached and enforced
+    /// # Returns
+    /// * `(MilestoneStatus, Amount)` - Milestone status after enforcement and the amount
+    ///   released to the creator, capped at what is left of the milestone
     ///
     /// # Errors
-    /// * `VotingPeriodNotActive` - Reveal period not ended
-    pub fn tally_votes(env: Env, dispute_id: u64) -> Result<(), Error> {
-        let mut dispute = get_dispute(&env, dispute_id)?;
+    /// * `InvalidInput` - Dispute not in resolved state
+    pub fn preview_enforcement(
+        env: Env,
+        dispute_id: u64,
+    ) -> Result<(MilestoneStatus, Amount), Error> {
+        let dispute = get_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Resolved {
+            return Err(Error::InvInput);
+        }
+
+        let milestone = get_milestone(&env, dispute.project_id, dispute.milestone_id)?;
+        let (status, release_amount) = resolution_outcome(&dispute, &milestone);
+        let remaining = milestone.amount - milestone.released_so_far;
+        Ok((status, release_amount.min(remaining).max(0)))
+    }
+
+    // ==================== Internal Helpers ====================
+
+    /// Tally revealed votes once the reveal period and grace have ended
+    fn tally(env: &Env, dispute_id: u64) -> Result<DisputeResolution, Error> {
+        let mut dispute = get_dispute(env, dispute_id)?;
 
         if dispute.status != DisputeStatus::Voting {
             return Err(Error::VoteNA);
@@ -1111,27 +2876,35 @@ impl EscrowContract {
             + shared::constants::VOTING_COMMIT_PERIOD
             + shared::constants::VOTING_REVEAL_PERIOD;
 
-        if current_time <= reveal_end {
-            return Err(Error::VoteNA); // Wait for reveal period to end
+        if current_time <= reveal_end + get_reveal_grace(env) {
+            return Err(Error::VoteNA); // Wait for reveal period and grace to end
         }
 
-        let assignments = get_juror_assignments(&env, dispute_id)?;
-        let mut votes: soroban_sdk::Map<soroban_sdk::Val, u32> = soroban_sdk::Map::new(&env);
-        let mut payloads: soroban_sdk::Map<soroban_sdk::Val, u32> = soroban_sdk::Map::new(&env);
+        let assignments = get_juror_assignments(env, dispute_id)?;
+        let mut votes: soroban_sdk::Map<soroban_sdk::Val, u32> = soroban_sdk::Map::new(env);
+        let mut payloads: soroban_sdk::Map<soroban_sdk::Val, u32> = soroban_sdk::Map::new(env);
         let mut winning_vote = DisputeResolution::NoRes;
         let mut winning_payload: u32 = 0;
         let mut max_votes: u32 = 0;
+        let mut revealed: u32 = 0;
 
         for juror in assignments.iter() {
-            if let Ok(commitment) = get_dispute_vote(&env, dispute_id, &juror) {
+            if let Ok(commitment) = get_dispute_vote(env, dispute_id, &juror) {
                 if commitment.revealed {
+                    revealed += 1;
                     let vote = commitment.vote;
-                    let v_val: soroban_sdk::Val = vote.into_val(&env);
+                    if vote == DisputeResolution::Abstain {
+                        continue;
+                    }
+                    let v_val: soroban_sdk::Val = vote.into_val(env);
                     let count = votes.get(v_val).unwrap_or(0);
                     votes.set(v_val, count + 1);
                     payloads.set(v_val, commitment.vote_payload);
 
-                    if count + 1 > max_votes {
+                    if count + 1 > max_votes
+                        || (count + 1 == max_votes
+                            && tie_break_rank(vote) < tie_break_rank(winning_vote))
+                    {
                         max_votes = count + 1;
                         winning_vote = vote;
                         winning_payload = commitment.vote_payload;
@@ -1140,184 +2913,302 @@ impl EscrowContract {
             }
         }
 
-        // Default to RefBackers if no one voted
-        let mut resolution = winning_vote;
-        if resolution == DisputeResolution::NoRes {
-            resolution = DisputeResolution::RefBackers;
+        let config = get_dispute_config(env);
+
+        // Too few reveals to carry legitimacy, or only abstentions: replace the
+        // panel instead of resolving
+        if (revealed as u64) * 10_000 < assignments.len() as u64 * MIN_REVEAL_QUORUM as u64
+            || max_votes == 0
+        {
+            Self::dissolve_panel(
+                env,
+                dispute_id,
+                &mut dispute,
+                &assignments,
+                config.slash_bps,
+            )?;
+            return Ok(DisputeResolution::NoRes);
         }
-        let resolution_payload = winning_payload;
 
-        let mut majority_jurors = Vec::new(&env);
+        let resolution = winning_vote;
+        let resolution_payload = winning_payload;
+        let mut majority_jurors = Vec::new(env);
+        let mut score_updates = Vec::new(env);
+        let mut forfeited_bonds: Amount = 0;
+        let mut slashed_total: Amount = 0;
 
         for juror in assignments.iter() {
+            let bond = take_juror_bond(env, dispute_id, &juror);
             let mut is_majority = false;
-            if let Ok(commitment) = get_dispute_vote(&env, dispute_id, &juror) {
+            let mut is_late = false;
+            let mut abstained = false;
+            if let Ok(commitment) = get_dispute_vote(env, dispute_id, &juror) {
                 if commitment.revealed && commitment.vote == resolution {
                     is_majority = true;
+                    is_late = commitment.late;
                 }
+                abstained = commitment.revealed && commitment.vote == DisputeResolution::Abstain;
             }
 
-            if is_majority {
-                majority_jurors.push_back(juror.clone());
-                let mut info = get_juror(&env, &juror)?;
+            if abstained {
+                // An explicit abstention is participation: bond back, no reward or slash
+                let mut info = get_juror(env, &juror)?;
+                info.active_disputes -= 1;
+                info.staked_amount += bond;
+                set_juror(env, &juror, &info);
+            } else if is_majority {
+                // Grace-period reveals count towards the outcome but earn no reward
+                if !is_late {
+                    majority_jurors.push_back(juror.clone());
+                    score_updates.push_back((juror.clone(), JUROR_SERVICE_SCORE));
+                }
+                let mut info = get_juror(env, &juror)?;
                 info.successful_votes += 1;
                 info.active_disputes -= 1;
-                set_juror(&env, &juror, &info);
+                info.staked_amount += bond;
+                set_juror(env, &juror, &info);
             } else {
-                let mut info = get_juror(&env, &juror)?;
+                let mut info = get_juror(env, &juror)?;
                 info.missed_votes += 1;
                 info.active_disputes -= 1;
-                set_juror(&env, &juror, &info);
+                set_juror(env, &juror, &info);
+                forfeited_bonds += bond;
 
-                // Slash 10% of MIN_JUROR_STAKE as a penalty
-                let slash_amount = shared::constants::MIN_JUROR_STAKE / 10;
-                Self::slash_juror(&env, juror.clone(), slash_amount, 0)?;
+                // Slash slash_bps of the juror's own stake as a penalty
+                slashed_total += Self::slash_juror(env, juror.clone(), config.slash_bps, 0)?;
+                score_updates.push_back((juror.clone(), -JUROR_SLASH_SCORE));
             }
         }
 
-        Self::reward_jurors(&env, &majority_jurors)?;
+        // Forfeited bonds join the pool shared by the majority
+        if forfeited_bonds > 0 {
+            let pool = get_dispute_fee_pool(env);
+            set_dispute_fee_pool(env, pool + forfeited_bonds);
+        }
+
+        // Held back until the outcome is final, so an appeal cannot leave the loser paid
+        let compensation =
+            Self::reserve_compensation(env, slashed_total, config.slash_to_winner_bps)?;
+        dispute.pending_compensation += compensation;
+        Self::reward_jurors(env, dispute.project_id, &majority_jurors)?;
+        report_juror_scores(env, &score_updates);
 
         dispute.resolution = resolution;
         dispute.resolution_payload = resolution_payload;
         dispute.status = DisputeStatus::Resolved;
         dispute.created_at = env.ledger().timestamp(); // Reset time for appeal window
-        set_dispute(&env, dispute_id, &dispute);
+        set_dispute(env, dispute_id, &dispute);
+        emit_dispute_status(
+            env,
+            dispute_id,
+            DisputeStatus::Voting,
+            DisputeStatus::Resolved,
+        );
 
         env.events()
             .publish((DISPUTE_RESOLVED,), (dispute_id, resolution));
 
-        Ok(())
+        Ok(resolution)
     }
 
-    /// Execute resolution after appeal window closes
+    /// Release a panel that missed the reveal quorum and seat a fresh one
     ///
-    /// # Arguments
-    /// * `env` - Execution environment
-    /// * `dispute_id` - Dispute identifier
-    ///
-    /// # Events
-    /// * `APPEAL_RESOLVED` - Emitted when final enforcement is done
-    ///
-    /// # Errors
-    /// * `InvalidInput` - Dispute not resolved
-    /// * `AppealWindowClosed` - Appeal window still open
-    pub fn execute_resolution(env: Env, dispute_id: u64) -> Result<(), Error> {
-        let mut dispute = get_dispute(&env, dispute_id)?;
+    /// Revealers get their bonds back without a reward; non-revealers are slashed
+    /// and forfeit their bonds. If no fresh panel can be seated yet, the dispute is
+    /// left for `select_jury` to retry.
+    fn dissolve_panel(
+        env: &Env,
+        dispute_id: u64,
+        dispute: &mut Dispute,
+        assignments: &Vec<Address>,
+        slash_bps: u32,
+    ) -> Result<(), Error> {
+        let mut score_updates = Vec::new(env);
+        let mut forfeited_bonds: Amount = 0;
+        for juror in assignments.iter() {
+            let bond = take_juror_bond(env, dispute_id, &juror);
+            let revealed = get_dispute_vote(env, dispute_id, &juror)
+                .map(|commitment| commitment.revealed)
+                .unwrap_or(false);
+
+            let mut info = get_juror(env, &juror)?;
+            info.active_disputes -= 1;
+            if revealed {
+                info.staked_amount += bond;
+                set_juror(env, &juror, &info);
+            } else {
+                info.missed_votes += 1;
+                set_juror(env, &juror, &info);
+                forfeited_bonds += bond;
+                Self::slash_juror(env, juror.clone(), slash_bps, 0)?;
+                score_updates.push_back((juror.clone(), -JUROR_SLASH_SCORE));
+            }
+        }
 
-        if dispute.status != DisputeStatus::Resolved {
-            return Err(Error::InvInput);
+        if forfeited_bonds > 0 {
+            let pool = get_dispute_fee_pool(env);
+            set_dispute_fee_pool(env, pool + forfeited_bonds);
         }
+        report_juror_scores(env, &score_updates);
 
-        let current_time = env.ledger().timestamp();
-        // Dispute resolution time-lock:
-        // - While appeals remain available, wait for the appeal window to elapse (and also satisfy the minimum lock).
-        // - Once max appeals are exhausted, enforcements still must wait for the resolution time-lock.
-        //
-        // `created_at` is reset in `tally_votes` and marks the start of these windows.
-        let required_delay = if dispute.appeal_count >= shared::constants::MAX_APPEALS as u32 {
-            shared::constants::RESOLUTION_TIME_LOCK
+        // An appeal round keeps its larger panel size
+        dispute.status = if dispute.appeal_count > 0 {
+            DisputeStatus::Appealed
         } else {
-            core::cmp::max(
-                shared::constants::RESOLUTION_TIME_LOCK,
-                shared::constants::APPEAL_WINDOW_PERIOD,
-            )
+            DisputeStatus::Pending
         };
+        set_dispute(env, dispute_id, dispute);
+        emit_dispute_status(env, dispute_id, DisputeStatus::Voting, dispute.status);
+        remove_juror_assignments(env, dispute_id);
 
-        if current_time <= dispute.created_at + required_delay {
-            return Err(Error::AppealWinCl); // Reusing for window still open
-        }
-
-        dispute.status = DisputeStatus::FinalResolved;
-        set_dispute(&env, dispute_id, &dispute);
-
-        let res = dispute.resolution;
-        Self::enforce_resolution(&env, &dispute, &res)?;
-        env.events().publish((APPEAL_RESOLVED,), (dispute_id, res));
+        env.events()
+            .publish((PANEL_DISSOLVED,), (dispute_id, assignments.clone()));
 
+        // select_jury makes no writes when it fails, so the dispute simply stays
+        // open for a later attempt once enough fresh jurors are available
+        let _ = Self::select_jury(env.clone(), dispute_id);
         Ok(())
     }
 
-    // ==================== Internal Helpers ====================
-
-    fn slash_juror(env: &Env, juror: Address, amount: Amount, _reason: u32) -> Result<(), Error> {
+    /// Slash `slash_bps` of a juror's stake into the fee pool, returning the amount taken
+    ///
+    /// Larger stakes lose more in absolute terms; at most the full stake is taken.
+    fn slash_juror(
+        env: &Env,
+        juror: Address,
+        slash_bps: u32,
+        _reason: u32,
+    ) -> Result<Amount, Error> {
         let mut info = get_juror(env, &juror)?;
 
-        let slashed = if info.staked_amount < amount {
-            info.staked_amount
-        } else {
-            amount
-        };
+        let slashed = pro_rata(info.staked_amount, slash_bps as i128, 10_000)
+            .ok_or(Error::InvInput)?
+            .min(info.staked_amount);
 
         info.staked_amount -= slashed;
+        info.total_slashed += slashed;
         set_juror(env, &juror, &info);
 
         let pool = get_dispute_fee_pool(env);
         set_dispute_fee_pool(env, pool + slashed);
 
         env.events().publish((JUROR_SLASHED,), (juror, slashed));
+        Ok(slashed)
+    }
+
+    /// Take the winning side's share of a tally's slashed stake out of the fee pool
+    ///
+    /// Returns the share, to be paid by `compensate_winner` once the dispute is final.
+    fn reserve_compensation(env: &Env, slashed: Amount, winner_bps: u32) -> Result<Amount, Error> {
+        let share = pro_rata(slashed, winner_bps as i128, 10_000).ok_or(Error::InvInput)?;
+        if share > 0 {
+            let pool = get_dispute_fee_pool(env);
+            set_dispute_fee_pool(env, pool - share);
+        }
+        Ok(share.max(0))
+    }
+
+    /// Pay a final dispute's reserved compensation to the side it was resolved for
+    ///
+    /// A release or partial release pays the creator; a refund credits the
+    /// escrow, in the juror token, so it can be returned to backers.
+    fn compensate_winner(env: &Env, dispute: &Dispute, share: Amount) -> Result<(), Error> {
+        if share <= 0 {
+            return Ok(());
+        }
+
+        let (dispute_id, project_id) = (dispute.id, dispute.project_id);
+        let token = get_juror_token(env)?;
+        let mut escrow = get_escrow(env, project_id)?;
+        let recipient = if dispute.resolution == DisputeResolution::RefBackers {
+            if token == escrow.token {
+                escrow.total_deposited += share;
+                set_escrow(env, project_id, &escrow);
+            } else {
+                let deposited = get_token_deposited(env, project_id, &token);
+                set_token_deposited(env, project_id, &token, deposited + share);
+            }
+            env.current_contract_address()
+        } else {
+            TokenClient::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &escrow.creator,
+                &share,
+            );
+            escrow.creator
+        };
+
+        env.events()
+            .publish((SLASH_COMPENSATED,), (dispute_id, recipient, share));
         Ok(())
     }
 
-    fn reward_jurors(env: &Env, majority_jurors: &Vec<Address>) -> Result<(), Error> {
-        let count = majority_jurors.len() as i128;
-        if count == 0 {
+    /// Share the fee pools among majority jurors in proportion to their stakes
+    ///
+    /// Global-pool rewards accrue apart from the stake, out of reach of later
+    /// slashes. Units left over by rounding down stay in the pool for later disputes.
+    fn reward_jurors(
+        env: &Env,
+        project_id: u64,
+        majority_jurors: &Vec<Address>,
+    ) -> Result<(), Error> {
+        let mut stakes = Vec::new(env);
+        let mut total_stake: Amount = 0;
+        for j in majority_jurors.iter() {
+            let stake = get_juror(env, &j)?.staked_amount;
+            total_stake = total_stake.checked_add(stake).ok_or(Error::InvInput)?;
+            stakes.push_back(stake);
+        }
+        if total_stake <= 0 {
             return Ok(());
         }
 
+        // Fees collected in a project's own dispute token are paid out directly,
+        // since they cannot be credited to stakes held in the global token.
+        if let Some(token) = get_project_dispute_token(env, project_id) {
+            let project_pool = get_project_fee_pool(env, project_id);
+            if project_pool > 0 {
+                let token_client = TokenClient::new(env, &token);
+                let mut paid: Amount = 0;
+                for (j, stake) in majority_jurors.iter().zip(stakes.iter()) {
+                    let reward =
+                        pro_rata(project_pool, stake, total_stake).ok_or(Error::InvInput)?;
+                    if reward > 0 {
+                        token_client.transfer(&env.current_contract_address(), &j, &reward);
+                        paid += reward;
+                    }
+                }
+                set_project_fee_pool(env, project_id, project_pool - paid);
+            }
+        }
+
         let pool = get_dispute_fee_pool(env);
         if pool > 0 {
-            let reward_per_juror = pool / count;
-            for j in majority_jurors.iter() {
+            let mut paid: Amount = 0;
+            for (j, stake) in majority_jurors.iter().zip(stakes.iter()) {
+                let reward = pro_rata(pool, stake, total_stake).ok_or(Error::InvInput)?;
                 let mut info = get_juror(env, &j)?;
-                info.staked_amount += reward_per_juror;
+                info.rewards_accrued += reward;
                 set_juror(env, &j, &info);
+                paid += reward;
             }
-            set_dispute_fee_pool(env, pool % count);
+            set_dispute_fee_pool(env, pool - paid);
         }
         Ok(())
     }
 
-    fn enforce_resolution(
-        env: &Env,
-        dispute: &Dispute,
-        resolution: &DisputeResolution,
-    ) -> Result<(), Error> {
+    fn enforce_resolution(env: &Env, dispute: &Dispute) -> Result<(), Error> {
         let mut escrow = get_escrow(env, dispute.project_id)?;
         let mut milestone = get_milestone(env, dispute.project_id, dispute.milestone_id)?;
 
-        let amount = milestone.amount;
-        let release_amount;
-
-        match resolution {
-            DisputeResolution::RelFunds => {
-                release_amount = amount;
-                milestone.status = MilestoneStatus::Approved;
-            }
-            DisputeResolution::RefBackers => {
-                release_amount = 0;
-                milestone.status = MilestoneStatus::Rejected;
-            }
-            DisputeResolution::PartRel => {
-                let pct = dispute.resolution_payload;
-                let p = (pct as i128).clamp(0, 10000);
-                release_amount = (amount * p) / 10000;
-                milestone.status = MilestoneStatus::Approved;
-            }
-            _ => {
-                release_amount = 0;
-            }
-        }
+        let (status, release_amount) = resolution_outcome(dispute, &milestone);
+        milestone.status = status;
 
+        // Capped at what is left of the milestone after earlier releases
+        let release_amount =
+            release_milestone_funds(env, &mut escrow, &mut milestone, release_amount)?;
         if release_amount > 0 {
-            let virtual_milestone = Milestone {
-                amount: release_amount,
-                ..milestone.clone()
-            };
-            let mut virtual_escrow = escrow.clone();
-            release_milestone_funds(env, &mut virtual_escrow, &virtual_milestone)?;
-            escrow.released_amount = virtual_escrow.released_amount;
-
-            let token_client = TokenClient::new(env, &escrow.token);
+            let token_client = TokenClient::new(env, &milestone.token);
             token_client.transfer(
                 &env.current_contract_address(),
                 &escrow.creator,
@@ -1333,6 +3224,9 @@ impl EscrowContract {
 
     /// File an appeal on a resolved dispute
     ///
+    /// The appellant pays the configured appeal fee times the number of this
+    /// appeal, so the first costs one fee, the second two, and so on.
+    ///
     /// # Arguments
     /// * `env` - Execution environment
     /// * `dispute_id` - Dispute identifier
@@ -1340,12 +3234,14 @@ impl EscrowContract {
     ///
     /// # Events
     /// * `DISPUTE_APPEALED` - Emitted when an appeal is successfully filed
+    /// * `DISPUTE_STATUS_CHANGED` - Emitted with the old and new status
     ///
     /// # Errors
     /// * `InvalidInput` - Dispute not in resolved state
     /// * `AppealWindowClosed` - Over time limit
     /// * `MaxAppealsReached` - Capped out
     pub fn file_appeal(env: Env, dispute_id: u64, appellant: Address) -> Result<(), Error> {
+        set_lock(&env)?;
         appellant.require_auth();
 
         let mut dispute = get_dispute(&env, dispute_id)?;
@@ -1363,33 +3259,44 @@ impl EscrowContract {
             return Err(Error::MaxAppeals);
         }
 
-        let token = get_juror_token(&env)?;
+        let token = get_dispute_token_for(&env, dispute.project_id)?;
         let token_client = TokenClient::new(&env, &token);
 
-        let fee = shared::constants::APPEAL_FEE;
+        // Each further appeal costs one more multiple of the base fee
+        let fee = get_dispute_config(&env)
+            .appeal_fee
+            .checked_mul(dispute.appeal_count as i128 + 1)
+            .ok_or(Error::InvInput)?;
 
         token_client.transfer(&appellant, &env.current_contract_address(), &fee);
 
-        let pool = get_dispute_fee_pool(&env);
-        set_dispute_fee_pool(&env, pool + fee);
+        if get_project_dispute_token(&env, dispute.project_id).is_some() {
+            let pool = get_project_fee_pool(&env, dispute.project_id);
+            set_project_fee_pool(&env, dispute.project_id, pool + fee);
+        } else {
+            let pool = get_dispute_fee_pool(&env);
+            set_dispute_fee_pool(&env, pool + fee);
+        }
 
         dispute.appeal_count += 1;
+        dispute.appellants.push_back(appellant.clone());
         dispute.status = DisputeStatus::Appealed;
         set_dispute(&env, dispute_id, &dispute);
-
-        // Reset the milestone to Pending so it can be re-submitted for validator votes
-        let mut milestone = get_milestone(&env, dispute.project_id, dispute.milestone_id)?;
-        milestone.status = MilestoneStatus::Pending;
-        milestone.approval_count = 0;
-        milestone.rejection_count = 0;
-        set_milestone(&env, dispute.project_id, dispute.milestone_id, &milestone);
-        clear_milestone_voters(&env, dispute.project_id, dispute.milestone_id);
+        emit_dispute_status(
+            &env,
+            dispute_id,
+            DisputeStatus::Resolved,
+            DisputeStatus::Appealed,
+        );
 
         env.events()
             .publish((DISPUTE_APPEALED,), (dispute_id, appellant));
 
+        // The appeal is decided by a fresh, larger panel with its own commit-reveal
+        // round; the milestone stays untouched until execute_resolution.
         Self::select_jury(env.clone(), dispute_id)?;
 
+        clear_lock(&env);
         Ok(())
     }
 
@@ -1398,6 +3305,17 @@ impl EscrowContract {
         is_paused(&env)
     }
 
+    /// Seconds until a paused contract may be resumed, 0 if not paused or already resumable
+    pub fn get_resume_eta(env: Env) -> u64 {
+        let state = get_pause_state(&env);
+        if !state.paused {
+            return 0;
+        }
+        state
+            .resume_not_before
+            .saturating_sub(env.ledger().timestamp())
+    }
+
     // ---------- Upgrade (time-locked, admin only, requires pause) ----------
     /// Schedule an upgrade. Admin only. Executable after UPGRADE_TIME_LOCK_SECS (48h).
     pub fn schedule_upgrade(
@@ -1405,60 +3323,19 @@ impl EscrowContract {
         admin: Address,
         new_wasm_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        let stored_admin = get_admin(&env)?;
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        admin.require_auth();
-        let now = env.ledger().timestamp();
-        let pending = PendingUpgrade {
-            wasm_hash: new_wasm_hash.clone(),
-            execute_not_before: now + UPGRADE_TIME_LOCK_SECS,
-        };
-        set_pending_upgrade(&env, &pending);
-        env.events().publish(
-            (UPGRADE_SCHEDULED,),
-            (admin, new_wasm_hash, pending.execute_not_before),
-        );
+        upgradeable::schedule_upgrade::<Self>(&env, &admin, new_wasm_hash)?;
         Ok(())
     }
 
     /// Execute a scheduled upgrade. Admin only. Contract must be paused. Only after time-lock.
     pub fn execute_upgrade(env: Env, admin: Address) -> Result<(), Error> {
-        let stored_admin = get_admin(&env)?;
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        admin.require_auth();
-        if !is_paused(&env) {
-            return Err(Error::UpgReqPause);
-        }
-        let pending = get_pending_upgrade(&env).ok_or(Error::UpgNotSched)?;
-        let now = env.ledger().timestamp();
-        if now < pending.execute_not_before {
-            return Err(Error::UpgTooEarly);
-        }
-        env.deployer()
-            .update_current_contract_wasm(pending.wasm_hash.clone());
-        clear_pending_upgrade(&env);
-        env.events()
-            .publish((UPGRADE_EXECUTED,), (admin, pending.wasm_hash));
+        upgradeable::execute_upgrade::<Self>(&env, &admin)?;
         Ok(())
     }
 
     /// Cancel a scheduled upgrade. Admin only.
     pub fn cancel_upgrade(env: Env, admin: Address) -> Result<(), Error> {
-        let stored_admin = get_admin(&env)?;
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        admin.require_auth();
-        if !has_pending_upgrade(&env) {
-            return Err(Error::UpgNotSched);
-        }
-        clear_pending_upgrade(&env);
-        env.events().publish((UPGRADE_CANCELLED,), admin);
-        Ok(())
+        upgradeable::cancel_upgrade::<Self>(&env, &admin)
     }
 
     /// Get pending upgrade info, if any.
@@ -1466,6 +3343,22 @@ impl EscrowContract {
         storage::get_pending_upgrade(&env)
     }
 
+    /// Seconds until the pending upgrade's time-lock expires, 0 if none is pending or it has expired
+    pub fn get_upgrade_eta(env: Env) -> u64 {
+        storage::get_pending_upgrade(&env)
+            .map(|pending| {
+                pending
+                    .execute_not_before
+                    .saturating_sub(env.ledger().timestamp())
+            })
+            .unwrap_or(0)
+    }
+
+    /// Get the live contract version, starting at 1 and bumped by each executed upgrade.
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
     /// Claim excess yield from the escrow contract
     /// Yield = Balance - (TotalDeposited - ReleasedAmount)
     pub fn claim_yield(
@@ -1473,10 +3366,11 @@ impl EscrowContract {
         project_id: u64,
         profit_dist_contract: Address,
     ) -> Result<(), Error> {
+        set_lock(&env)?;
         let escrow = get_escrow(&env, project_id)?;
         escrow.creator.require_auth();
 
-        if is_paused(&env) {
+        if is_paused_for(&env, project_id) {
             return Err(Error::Paused);
         }
 
@@ -1535,30 +3429,329 @@ impl EscrowContract {
             (project_id, total_yield, fee_amount, investor_amount),
         );
 
+        clear_lock(&env);
         Ok(())
     }
 }
 
+impl Upgradeable for EscrowContract {
+    fn upgrade_admin(env: &Env) -> Result<Address, Error> {
+        storage::get_admin(env)
+    }
+
+    fn is_paused(env: &Env) -> bool {
+        storage::is_paused(env)
+    }
+
+    fn pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+        storage::get_pending_upgrade(env)
+    }
+
+    fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade) {
+        storage::set_pending_upgrade(env, pending)
+    }
+
+    fn clear_pending_upgrade(env: &Env) {
+        storage::clear_pending_upgrade(env)
+    }
+
+    fn version(env: &Env) -> u32 {
+        storage::get_version(env)
+    }
+
+    fn set_version(env: &Env, version: u32) {
+        storage::set_version(env, version)
+    }
+}
+
+/// Lower median of the approved percentages, in basis points
+fn median_bps(approved_bps: &Vec<u32>) -> u32 {
+    let mut sorted = approved_bps.clone();
+    for i in 1..sorted.len() {
+        let mut j = i;
+        while j > 0 && sorted.get(j - 1).unwrap() > sorted.get(j).unwrap() {
+            let prev = sorted.get(j - 1).unwrap();
+            sorted.set(j - 1, sorted.get(j).unwrap());
+            sorted.set(j, prev);
+            j -= 1;
+        }
+    }
+    sorted
+        .get((sorted.len().saturating_sub(1)) / 2)
+        .unwrap_or(FULL_APPROVAL_BPS)
+}
+
 /// Helper function to release milestone funds
+/// Record a release of up to `amount` from a milestone, returning what was actually released
+///
+/// The release is capped at the part of the milestone not yet paid out, so partial
+/// and full release paths combined never exceed the milestone amount.
 fn release_milestone_funds(
-    _env: &Env,
+    env: &Env,
     escrow: &mut EscrowInfo,
-    milestone: &Milestone,
-) -> Result<(), Error> {
+    milestone: &mut Milestone,
+    amount: Amount,
+) -> Result<Amount, Error> {
+    let remaining = milestone.amount - milestone.released_so_far;
+    let release = amount.min(remaining);
+    if release <= 0 {
+        return Ok(0);
+    }
+
     // Verify funds are not released more than once
-    let new_released = escrow
-        .released_amount
-        .checked_add(milestone.amount)
-        .ok_or(Error::InvInput)?;
+    let (deposited, released) = token_totals(env, escrow, &milestone.token);
+    let new_released = released.checked_add(release).ok_or(Error::InvInput)?;
 
-    if new_released > escrow.total_deposited {
+    if new_released > deposited {
         return Err(Error::EscrowInsuf);
     }
 
-    escrow.released_amount = new_released;
+    if milestone.token == escrow.token {
+        escrow.released_amount = new_released;
+    } else {
+        set_token_released(env, escrow.project_id, &milestone.token, new_released);
+    }
+    milestone.released_so_far += release;
+    Ok(release)
+}
+
+/// Deposited and released totals of a project in one token
+///
+/// The escrow's default token is tracked on `EscrowInfo`; other milestone tokens in storage.
+fn token_totals(env: &Env, escrow: &EscrowInfo, token: &Address) -> (Amount, Amount) {
+    if *token == escrow.token {
+        (escrow.total_deposited, escrow.released_amount)
+    } else {
+        (
+            get_token_deposited(env, escrow.project_id, token),
+            get_token_released(env, escrow.project_id, token),
+        )
+    }
+}
+
+/// Milestone status and requested release for enforcing a dispute's resolution
+///
+/// The release is not yet capped by earlier payouts; see `release_milestone_funds`.
+fn resolution_outcome(dispute: &Dispute, milestone: &Milestone) -> (MilestoneStatus, Amount) {
+    match dispute.resolution {
+        DisputeResolution::RelFunds => (MilestoneStatus::Approved, milestone.amount),
+        DisputeResolution::RefBackers => (MilestoneStatus::Rejected, 0),
+        DisputeResolution::PartRel => {
+            let p = (dispute.resolution_payload as i128).clamp(0, 10000);
+            (MilestoneStatus::Approved, (milestone.amount * p) / 10000)
+        }
+        _ => (milestone.status, 0),
+    }
+}
+
+/// Hash a vote commitment. Preimage: variant_index + (optional payload) + salt
+fn commitment_hash(
+    env: &Env,
+    vote: DisputeResolution,
+    payload: u32,
+    salt: &soroban_sdk::Bytes,
+) -> Result<Hash, Error> {
+    let mut b = soroban_sdk::Bytes::new(env);
+    match vote {
+        DisputeResolution::RelFunds => b.push_back(0),
+        DisputeResolution::RefBackers => b.push_back(1),
+        DisputeResolution::PartRel => {
+            b.push_back(2);
+            for byte in payload.to_be_bytes() {
+                b.push_back(byte);
+            }
+        }
+        DisputeResolution::Abstain => b.push_back(3),
+        DisputeResolution::NoRes => return Err(Error::InvInput),
+    }
+    b.append(salt);
+
+    Ok(env.crypto().sha256(&b).into())
+}
+
+/// Reject creators below the configured reputation; a no-op while the gate is disabled
+fn require_creator_reputation(env: &Env, creator: &Address) -> Result<(), Error> {
+    let min_score = match get_min_creator_reputation(env) {
+        Some(min_score) => min_score,
+        None => return Ok(()),
+    };
+    let reputation_contract = get_reputation_contract(env).ok_or(Error::NotInit)?;
+
+    // Unregistered creators have no profile and are treated as below the threshold
+    let client = ReputationClient::new(env, &reputation_contract);
+    match client.try_get_profile(creator) {
+        Ok(Ok(profile)) if profile.score >= min_score => Ok(()),
+        _ => Err(Error::RepTooLow),
+    }
+}
+
+/// Drop the per-juror vote records of a finished dispute; the `Dispute` itself is kept
+///
+/// Jurors' `active_disputes` counters are already settled by the tally.
+fn clear_dispute_votes(env: &Env, dispute_id: u64) {
+    for juror in get_past_jurors(env, dispute_id).iter() {
+        remove_dispute_vote(env, dispute_id, &juror);
+    }
+    remove_juror_assignments(env, dispute_id);
+    remove_past_jurors(env, dispute_id);
+}
+
+/// A backer's contribution to a project as reported by the project-launch contract
+///
+/// `None` when no contract is configured or the call fails.
+fn backer_contribution(env: &Env, project_id: u64, backer: &Address) -> Option<Amount> {
+    let project_launch = get_project_launch(env)?;
+    let args: Vec<soroban_sdk::Val> =
+        soroban_sdk::vec![env, project_id.into_val(env), backer.into_val(env)];
+    match env.try_invoke_contract::<Amount, soroban_sdk::Error>(
+        &project_launch,
+        &soroban_sdk::Symbol::new(env, "get_user_contribution"),
+        args,
+    ) {
+        Ok(Ok(contribution)) => Some(contribution),
+        _ => None,
+    }
+}
+
+/// Whether a juror backs the disputed project beyond `MAX_JUROR_CONTRIBUTION`
+///
+/// Jurors are not excluded while the contribution cannot be read.
+fn is_significant_backer(env: &Env, dispute: &Dispute, juror: &Address) -> bool {
+    backer_contribution(env, dispute.project_id, juror)
+        .is_some_and(|contribution| contribution > shared::constants::MAX_JUROR_CONTRIBUTION)
+}
+
+/// Reject deposits made after the escrow's deposit deadline, if it has one
+fn require_deposit_window(env: &Env, escrow: &EscrowInfo) -> Result<(), Error> {
+    if escrow.deposit_deadline != 0 && env.ledger().timestamp() > escrow.deposit_deadline {
+        return Err(Error::DepositWindowClosed);
+    }
+    Ok(())
+}
+
+/// Require auth from the platform admin or the project's operator
+fn require_project_admin(env: &Env, project_id: u64, caller: &Address) -> Result<(), Error> {
+    let is_admin = get_admin(env)? == *caller;
+    if !is_admin && get_project_operator(env, project_id).as_ref() != Some(caller) {
+        return Err(Error::Unauthorized);
+    }
+    caller.require_auth();
+    Ok(())
+}
+
+/// Majority votes as basis points of all tallied votes, 0 when there are none
+fn win_rate_bps(successful: u32, missed: u32) -> u32 {
+    let total = successful as u64 + missed as u64;
+    if total == 0 {
+        return 0;
+    }
+    (successful as u64 * 10_000 / total) as u32
+}
+
+/// Store a project's new validator set, discarding the old set's votes on submitted milestones
+///
+/// Every roster change goes through here so stale votes never count against the new set.
+fn replace_validators(
+    env: &Env,
+    project_id: u64,
+    escrow: &mut EscrowInfo,
+    validators: Vec<Address>,
+) -> Result<(), Error> {
+    clear_pending_milestone_votes(env, project_id)?;
+    escrow.validators = validators;
+    set_escrow(env, project_id, escrow);
+    Ok(())
+}
+
+/// Discard votes cast on a project's submitted milestones by its current validators
+fn clear_pending_milestone_votes(env: &Env, project_id: u64) -> Result<(), Error> {
+    for milestone_id in 0..get_milestone_counter(env, project_id)? {
+        let milestone = get_milestone(env, project_id, milestone_id)?;
+        if milestone.status == MilestoneStatus::Submitted {
+            set_milestone_votes(env, project_id, milestone_id, 0, 0);
+            clear_milestone_voters(env, project_id, milestone_id);
+        }
+    }
+    Ok(())
+}
+
+/// Reject users the configured reputation contract reports as banned
+///
+/// Fails open: a reputation contract that cannot answer `is_banned` (an older
+/// deployment, or one configured only for badges) has no bans to enforce.
+fn require_not_banned(env: &Env, user: &Address) -> Result<(), Error> {
+    if let Some(reputation_contract) = get_reputation_contract(env) {
+        let client = ReputationClient::new(env, &reputation_contract);
+        if let Ok(Ok(true)) = client.try_is_banned(user) {
+            return Err(Error::Unauthorized);
+        }
+    }
+    Ok(())
+}
+
+/// Authorize a vote action by the juror or the juror's approved delegate
+fn require_juror_or_delegate(env: &Env, juror: &Address, caller: &Address) -> Result<(), Error> {
+    if caller != juror && get_juror_delegate(env, juror).as_ref() != Some(caller) {
+        return Err(Error::Unauthorized);
+    }
+    caller.require_auth();
     Ok(())
 }
 
+/// Grant the creator the MilestoneAchiever badge, if a reputation contract is configured.
+/// Failures (e.g. the badge was already awarded) never block the milestone approval.
+fn award_milestone_badge(env: &Env, creator: &Address) {
+    if let Some(reputation_contract) = get_reputation_contract(env) {
+        let client = ReputationClient::new(env, &reputation_contract);
+        let _ = client.try_grant_badge(
+            &env.current_contract_address(),
+            creator,
+            &BadgeType::MilestoneAchiever,
+        );
+    }
+}
+
+/// Precedence of a resolution when votes tie, lowest first
+///
+/// Refunding backers is the conservative outcome, so it wins any tie; a partial
+/// release beats a full one.
+fn tie_break_rank(resolution: DisputeResolution) -> u32 {
+    match resolution {
+        DisputeResolution::RefBackers => 0,
+        DisputeResolution::PartRel => 1,
+        DisputeResolution::RelFunds => 2,
+        DisputeResolution::NoRes => 3,
+        DisputeResolution::Abstain => 4,
+    }
+}
+
+/// Publish a dispute's status transition, with statuses as their `u32` discriminants
+///
+/// A newly opened dispute reports `Pending` as both its old and new status.
+fn emit_dispute_status(env: &Env, dispute_id: u64, old: DisputeStatus, new: DisputeStatus) {
+    env.events().publish(
+        (DISPUTE_STATUS_CHANGED,),
+        (dispute_id, old as u32, new as u32),
+    );
+}
+
+/// Report juror score changes to the reputation contract, if one is configured.
+/// Updates go out in as few calls as the batch limit allows, and failures (e.g. a
+/// juror without a profile) never block the tally.
+fn report_juror_scores(env: &Env, updates: &Vec<(Address, i128)>) {
+    let Some(reputation_contract) = get_reputation_contract(env) else {
+        return;
+    };
+    let client = ReputationClient::new(env, &reputation_contract);
+    let mut start = 0;
+    while start < updates.len() {
+        let end = core::cmp::min(start + MAX_BATCH_SIZE, updates.len());
+        let _ = client
+            .try_grant_score_updates(&env.current_contract_address(), &updates.slice(start..end));
+        start = end;
+    }
+}
+
 fn required_emergency_approvals(escrow: &EscrowInfo) -> u32 {
     let required = (escrow.validators.len() * escrow.approval_threshold) / 10_000;
     if required == 0 {
@@ -1,6 +1,7 @@
 use shared::errors::Error;
 use shared::types::{
-    Amount, Dispute, EscrowInfo, JurorInfo, Milestone, PauseState, PendingUpgrade, VoteCommitment,
+    Amount, Dispute, DisputeConfig, DisputeStatus, EscrowInfo, Hash, JurorInfo, Milestone,
+    MilestoneStatus, PauseState, PendingUpgrade, VoteCommitment,
 };
 use soroban_sdk::{Address, Env, Vec};
 
@@ -11,18 +12,39 @@ const ESCROW_PREFIX: &str = "escrow";
 const MILESTONE_PREFIX: &str = "milestone";
 const MILESTONE_COUNTER_PREFIX: &str = "m_counter";
 const VALIDATOR_VOTE_PREFIX: &str = "v_vote";
+const VOTE_REASON_PREFIX: &str = "v_reason";
+const APPROVAL_BPS_PREFIX: &str = "m_bps";
+const VALIDATOR_HISTORY_PREFIX: &str = "v_history";
+const TOKEN_DEPOSITED_PREFIX: &str = "tok_dep";
+const TOKEN_RELEASED_PREFIX: &str = "tok_rel";
 const ADMIN_KEY: &str = "admin";
 
 // Dispute Storage Keys
 const JUROR_TOKEN_KEY: &str = "j_token";
 const DISPUTE_ID_KEY: &str = "d_id";
 const DISPUTE_FEE_POOL: &str = "d_fee_pool";
+const PROJECT_DISPUTE_TOKEN_PREFIX: &str = "p_d_token";
+const PROJECT_OPERATOR_PREFIX: &str = "p_operator";
+const PROJECT_FEE_POOL_PREFIX: &str = "p_fee_pool";
+const REVEAL_GRACE_KEY: &str = "rev_grace";
+const MILESTONE_LIMIT_KEY: &str = "m_limit";
+const MIN_MILESTONE_AMOUNT_KEY: &str = "m_min_amt";
+const PROJECT_PAUSED_PREFIX: &str = "p_paused";
+const DISPUTE_CONFIG_KEY: &str = "d_config";
 const DISPUTE_PREFIX: &str = "dispute";
+const MILESTONE_DISPUTE_PREFIX: &str = "m_dispute";
 const JUROR_PREFIX: &str = "juror";
+const JUROR_DELEGATE_PREFIX: &str = "j_deleg";
+const JUROR_BOND_PREFIX: &str = "j_bond";
 const DISPUTE_VOTE_PREFIX: &str = "d_vote";
 const JUROR_ASSIGNMENTS_PREFIX: &str = "j_assign";
+const PAST_JURORS_PREFIX: &str = "j_past";
 const ACTIVE_JURORS_KEY: &str = "act_jurors";
+const TOTAL_STAKED_KEY: &str = "j_staked";
 const EMERGENCY_WITHDRAW_PREFIX: &str = "emg_withdraw";
+const REPUTATION_CONTRACT_KEY: &str = "rep_contract";
+const MIN_CREATOR_REP_KEY: &str = "min_cr_rep";
+const PROJECT_LAUNCH_KEY: &str = "prj_launch";
 
 /// Store platform admin
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -93,12 +115,13 @@ pub fn get_milestone_counter(env: &Env, project_id: u64) -> Result<u64, Error> {
         .ok_or(Error::NotFound)
 }
 
-/// Record that a validator voted on a milestone
+/// Record a validator's current vote on a milestone (approved basis points, 0 for a rejection)
 pub fn set_validator_vote(
     env: &Env,
     project_id: u64,
     milestone_id: u64,
     validator: &Address,
+    approve_bps: u32,
 ) -> Result<(), Error> {
     let key = (
         VALIDATOR_VOTE_PREFIX,
@@ -106,10 +129,32 @@ pub fn set_validator_vote(
         milestone_id,
         validator.clone(),
     );
-    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().set(&key, &approve_bps);
+
+    // A changed vote replaces the earlier decision in the validator's history
+    let mut history = get_validator_history(env, project_id, validator);
+    remove_history_entry(&mut history, milestone_id);
+    history.push_back((milestone_id, approve_bps > 0));
+    set_validator_history(env, project_id, validator, &history);
     Ok(())
 }
 
+/// Retrieve a validator's current vote on a milestone, if any
+pub fn get_validator_vote(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    validator: &Address,
+) -> Option<u32> {
+    let key = (
+        VALIDATOR_VOTE_PREFIX,
+        project_id,
+        milestone_id,
+        validator.clone(),
+    );
+    env.storage().persistent().get(&key)
+}
+
 /// Check if a validator has already voted on a milestone
 pub fn has_validator_voted(
     env: &Env,
@@ -126,15 +171,115 @@ pub fn has_validator_voted(
     Ok(env.storage().persistent().has(&key))
 }
 
+/// Record the hash of the reason a validator gave for their current vote
+pub fn set_vote_reason(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    validator: &Address,
+    reason_hash: &Hash,
+) {
+    let key = (
+        VOTE_REASON_PREFIX,
+        project_id,
+        milestone_id,
+        validator.clone(),
+    );
+    env.storage().persistent().set(&key, reason_hash);
+}
+
+/// Retrieve the reason hash a validator gave for their current vote, if any
+pub fn get_vote_reason(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    validator: &Address,
+) -> Option<Hash> {
+    let key = (
+        VOTE_REASON_PREFIX,
+        project_id,
+        milestone_id,
+        validator.clone(),
+    );
+    env.storage().persistent().get(&key)
+}
+
+/// Drop the reason a validator gave for a vote they are replacing
+pub fn remove_vote_reason(env: &Env, project_id: u64, milestone_id: u64, validator: &Address) {
+    let key = (
+        VOTE_REASON_PREFIX,
+        project_id,
+        milestone_id,
+        validator.clone(),
+    );
+    env.storage().persistent().remove(&key);
+}
+
+/// Retrieve a validator's `(milestone_id, approve)` decisions on a project
+pub fn get_validator_history(env: &Env, project_id: u64, validator: &Address) -> Vec<(u64, bool)> {
+    let key = (VALIDATOR_HISTORY_PREFIX, project_id, validator.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_validator_history(
+    env: &Env,
+    project_id: u64,
+    validator: &Address,
+    history: &Vec<(u64, bool)>,
+) {
+    let key = (VALIDATOR_HISTORY_PREFIX, project_id, validator.clone());
+    env.storage().persistent().set(&key, history);
+}
+
+/// Drop the decision recorded for a milestone, if any
+fn remove_history_entry(history: &mut Vec<(u64, bool)>, milestone_id: u64) {
+    if let Some(index) = history.iter().position(|(id, _)| id == milestone_id) {
+        history.remove(index as u32);
+    }
+}
+
 /// Clear all validator votes for a milestone (used when resubmitting)
 pub fn clear_milestone_voters(env: &Env, project_id: u64, milestone_id: u64) {
     // Get the escrow to know how many validators there are
     if let Ok(escrow) = get_escrow(env, project_id) {
         for validator in escrow.validators.iter() {
+            let mut history = get_validator_history(env, project_id, &validator);
+            let len = history.len();
+            remove_history_entry(&mut history, milestone_id);
+            if history.len() != len {
+                set_validator_history(env, project_id, &validator, &history);
+            }
+
+            remove_vote_reason(env, project_id, milestone_id, &validator);
             let key = (VALIDATOR_VOTE_PREFIX, project_id, milestone_id, validator);
             env.storage().persistent().remove(&key);
         }
     }
+    let key = (APPROVAL_BPS_PREFIX, project_id, milestone_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Store the approved percentages (basis points) cast on a milestone
+pub fn set_milestone_approval_bps(
+    env: &Env,
+    project_id: u64,
+    milestone_id: u64,
+    approved_bps: &Vec<u32>,
+) {
+    let key = (APPROVAL_BPS_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, approved_bps);
+}
+
+/// Retrieve the approved percentages cast on a milestone
+pub fn get_milestone_approval_bps(env: &Env, project_id: u64, milestone_id: u64) -> Vec<u32> {
+    let key = (APPROVAL_BPS_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get::<(&str, u64, u64), Vec<u32>>(&key)
+        .unwrap_or(Vec::new(env))
 }
 
 /// Update vote counts for a milestone (alternative approach if needed)
@@ -163,13 +308,76 @@ pub fn get_total_milestone_amount(env: &Env, project_id: u64) -> Result<Amount,
 
     for milestone_id in 0..counter {
         if let Ok(milestone) = get_milestone(env, project_id, milestone_id) {
-            total = total.checked_add(milestone.amount).ok_or(Error::InvInput)?;
+            total = total
+                .checked_add(allocated_amount(&milestone))
+                .ok_or(Error::InvInput)?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Part of a milestone's amount still allocated to it; a cancelled one keeps only what it paid out
+fn allocated_amount(milestone: &Milestone) -> Amount {
+    if milestone.status == MilestoneStatus::Cancelled {
+        milestone.released_so_far
+    } else {
+        milestone.amount
+    }
+}
+
+/// Sum the amounts of a project's milestones paid in `token`
+pub fn get_token_milestone_amount(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+) -> Result<Amount, Error> {
+    let counter = get_milestone_counter(env, project_id)?;
+    let mut total: Amount = 0;
+
+    for milestone_id in 0..counter {
+        if let Ok(milestone) = get_milestone(env, project_id, milestone_id) {
+            if milestone.token == *token {
+                total = total
+                    .checked_add(allocated_amount(&milestone))
+                    .ok_or(Error::InvInput)?;
+            }
         }
     }
 
     Ok(total)
 }
 
+/// Store the amount deposited in a token other than the escrow's default
+pub fn set_token_deposited(env: &Env, project_id: u64, token: &Address, amount: Amount) {
+    let key = (TOKEN_DEPOSITED_PREFIX, project_id, token.clone());
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the amount deposited in a token other than the escrow's default
+pub fn get_token_deposited(env: &Env, project_id: u64, token: &Address) -> Amount {
+    let key = (TOKEN_DEPOSITED_PREFIX, project_id, token.clone());
+    env.storage()
+        .persistent()
+        .get::<(&str, u64, Address), Amount>(&key)
+        .unwrap_or(0)
+}
+
+/// Store the amount released in a token other than the escrow's default
+pub fn set_token_released(env: &Env, project_id: u64, token: &Address, amount: Amount) {
+    let key = (TOKEN_RELEASED_PREFIX, project_id, token.clone());
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieve the amount released in a token other than the escrow's default
+pub fn get_token_released(env: &Env, project_id: u64, token: &Address) -> Amount {
+    let key = (TOKEN_RELEASED_PREFIX, project_id, token.clone());
+    env.storage()
+        .persistent()
+        .get::<(&str, u64, Address), Amount>(&key)
+        .unwrap_or(0)
+}
+
 // ==================== Dispute Resolution Storage ====================
 
 /// Store the token used for juror staking
@@ -185,6 +393,121 @@ pub fn get_juror_token(env: &Env) -> Result<Address, Error> {
         .ok_or(Error::NotInit)
 }
 
+/// Store the operator allowed to manage a project's validators
+pub fn set_project_operator(env: &Env, project_id: u64, operator: &Address) {
+    let key = (PROJECT_OPERATOR_PREFIX, project_id);
+    env.storage().persistent().set(&key, operator);
+}
+
+/// Retrieve a project's operator, if the creator appointed one
+pub fn get_project_operator(env: &Env, project_id: u64) -> Option<Address> {
+    let key = (PROJECT_OPERATOR_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store a per-project dispute token override
+pub fn set_project_dispute_token(env: &Env, project_id: u64, token: &Address) {
+    let key = (PROJECT_DISPUTE_TOKEN_PREFIX, project_id);
+    env.storage().persistent().set(&key, token);
+}
+
+/// Retrieve the per-project dispute token override, if any
+pub fn get_project_dispute_token(env: &Env, project_id: u64) -> Option<Address> {
+    let key = (PROJECT_DISPUTE_TOKEN_PREFIX, project_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Remove the per-project dispute token override
+pub fn clear_project_dispute_token(env: &Env, project_id: u64) {
+    let key = (PROJECT_DISPUTE_TOKEN_PREFIX, project_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Retrieve the dispute token for a project, falling back to the global juror token
+pub fn get_dispute_token_for(env: &Env, project_id: u64) -> Result<Address, Error> {
+    match get_project_dispute_token(env, project_id) {
+        Some(token) => Ok(token),
+        None => get_juror_token(env),
+    }
+}
+
+/// Retrieve the fee pool held in a project's overridden dispute token
+pub fn get_project_fee_pool(env: &Env, project_id: u64) -> Amount {
+    let key = (PROJECT_FEE_POOL_PREFIX, project_id);
+    env.storage()
+        .persistent()
+        .get::<(&str, u64), Amount>(&key)
+        .unwrap_or(0)
+}
+
+/// Update the fee pool held in a project's overridden dispute token
+pub fn set_project_fee_pool(env: &Env, project_id: u64, amount: Amount) {
+    let key = (PROJECT_FEE_POOL_PREFIX, project_id);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Store the reveal grace period in seconds
+pub fn set_reveal_grace(env: &Env, grace: u64) {
+    env.storage().instance().set(&REVEAL_GRACE_KEY, &grace);
+}
+
+/// Retrieve the reveal grace period, defaulting to REVEAL_GRACE_PERIOD
+pub fn get_reveal_grace(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get::<&str, u64>(&REVEAL_GRACE_KEY)
+        .unwrap_or(shared::constants::REVEAL_GRACE_PERIOD)
+}
+
+/// Store the deployment's milestone limit per project
+pub fn set_milestone_limit(env: &Env, limit: u64) {
+    env.storage().instance().set(&MILESTONE_LIMIT_KEY, &limit);
+}
+
+/// Retrieve the milestone limit per project, defaulting to MAX_MILESTONES_PER_PROJECT
+pub fn get_milestone_limit(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get::<&str, u64>(&MILESTONE_LIMIT_KEY)
+        .unwrap_or(shared::constants::MAX_MILESTONES_PER_PROJECT)
+}
+
+/// Store the deployment's minimum milestone amount
+pub fn set_min_milestone_amount(env: &Env, amount: Amount) {
+    env.storage()
+        .instance()
+        .set(&MIN_MILESTONE_AMOUNT_KEY, &amount);
+}
+
+/// Retrieve the minimum milestone amount, defaulting to MIN_MILESTONE_AMOUNT
+pub fn get_min_milestone_amount(env: &Env) -> Amount {
+    env.storage()
+        .instance()
+        .get::<&str, Amount>(&MIN_MILESTONE_AMOUNT_KEY)
+        .unwrap_or(shared::constants::MIN_MILESTONE_AMOUNT)
+}
+
+/// Store the dispute parameters
+pub fn set_dispute_config(env: &Env, config: &DisputeConfig) {
+    env.storage().instance().set(&DISPUTE_CONFIG_KEY, config);
+}
+
+/// Retrieve the dispute parameters, defaulting to the shared constants
+pub fn get_dispute_config(env: &Env) -> DisputeConfig {
+    env.storage()
+        .instance()
+        .get::<&str, DisputeConfig>(&DISPUTE_CONFIG_KEY)
+        .unwrap_or(DisputeConfig {
+            min_juror_stake: shared::constants::MIN_JUROR_STAKE,
+            jury_size: shared::constants::JURY_SIZE,
+            appeal_jury_size: shared::constants::APPEAL_JURY_SIZE,
+            slash_bps: shared::constants::JUROR_SLASH_BPS,
+            slash_to_winner_bps: shared::constants::SLASH_TO_WINNER_BPS,
+            appeal_fee: shared::constants::APPEAL_FEE,
+            juror_bond: shared::constants::PER_DISPUTE_BOND,
+        })
+}
+
 /// Store the next dispute ID
 pub fn set_next_dispute_id(env: &Env, id: u64) {
     env.storage().persistent().set(&DISPUTE_ID_KEY, &id);
@@ -226,8 +549,32 @@ pub fn get_dispute(env: &Env, dispute_id: u64) -> Result<Dispute, Error> {
         .ok_or(Error::DispNF)
 }
 
+/// Link a milestone to the latest dispute opened on it
+pub fn set_milestone_dispute(env: &Env, project_id: u64, milestone_id: u64, dispute_id: u64) {
+    let key = (MILESTONE_DISPUTE_PREFIX, project_id, milestone_id);
+    env.storage().persistent().set(&key, &dispute_id);
+}
+
+/// Check whether a milestone has a dispute that is not yet final
+pub fn has_open_milestone_dispute(env: &Env, project_id: u64, milestone_id: u64) -> bool {
+    let key = (MILESTONE_DISPUTE_PREFIX, project_id, milestone_id);
+    env.storage()
+        .persistent()
+        .get::<(&str, u64, u64), u64>(&key)
+        .and_then(|dispute_id| get_dispute(env, dispute_id).ok())
+        .is_some_and(|dispute| dispute.status != DisputeStatus::FinalResolved)
+}
+
 /// Store a juror's information
+///
+/// Keeps the running stake total in step with the change to this juror's stake.
 pub fn set_juror(env: &Env, juror_address: &Address, juror: &JurorInfo) {
+    let previous = get_juror(env, juror_address).map_or(0, |info| info.staked_amount);
+    set_total_juror_stake(
+        env,
+        get_total_juror_stake(env) - previous + juror.staked_amount,
+    );
+
     let key = (JUROR_PREFIX, juror_address.clone());
     env.storage().persistent().set(&key, juror);
 }
@@ -243,10 +590,58 @@ pub fn get_juror(env: &Env, juror_address: &Address) -> Result<JurorInfo, Error>
 
 /// Remove a juror's information
 pub fn remove_juror(env: &Env, juror_address: &Address) {
+    if let Ok(info) = get_juror(env, juror_address) {
+        set_total_juror_stake(env, get_total_juror_stake(env) - info.staked_amount);
+    }
+
     let key = (JUROR_PREFIX, juror_address.clone());
     env.storage().persistent().remove(&key);
 }
 
+fn set_total_juror_stake(env: &Env, amount: Amount) {
+    env.storage().persistent().set(&TOTAL_STAKED_KEY, &amount);
+}
+
+/// Retrieve the sum of all registered jurors' stakes
+pub fn get_total_juror_stake(env: &Env) -> Amount {
+    env.storage()
+        .persistent()
+        .get::<&str, Amount>(&TOTAL_STAKED_KEY)
+        .unwrap_or(0)
+}
+
+/// Store the delegate allowed to commit and reveal on a juror's behalf
+pub fn set_juror_delegate(env: &Env, juror: &Address, delegate: &Address) {
+    let key = (JUROR_DELEGATE_PREFIX, juror.clone());
+    env.storage().persistent().set(&key, delegate);
+}
+
+/// Retrieve a juror's delegate, if any
+pub fn get_juror_delegate(env: &Env, juror: &Address) -> Option<Address> {
+    let key = (JUROR_DELEGATE_PREFIX, juror.clone());
+    env.storage()
+        .persistent()
+        .get::<(&str, Address), Address>(&key)
+}
+
+/// Record the bond a juror locked for a dispute
+pub fn set_juror_bond(env: &Env, dispute_id: u64, juror: &Address, bond: Amount) {
+    let key = (JUROR_BOND_PREFIX, dispute_id, juror.clone());
+    env.storage().persistent().set(&key, &bond);
+}
+
+/// Remove and return the bond a juror locked for a dispute (0 if none)
+pub fn take_juror_bond(env: &Env, dispute_id: u64, juror: &Address) -> Amount {
+    let key = (JUROR_BOND_PREFIX, dispute_id, juror.clone());
+    let bond = env
+        .storage()
+        .persistent()
+        .get::<(&str, u64, Address), Amount>(&key)
+        .unwrap_or(0);
+    env.storage().persistent().remove(&key);
+    bond
+}
+
 /// Store a vote commitment for a dispute
 pub fn set_dispute_vote(
     env: &Env,
@@ -271,6 +666,12 @@ pub fn get_dispute_vote(
         .ok_or(Error::NotFound)
 }
 
+/// Remove a vote commitment for a dispute
+pub fn remove_dispute_vote(env: &Env, dispute_id: u64, juror_address: &Address) {
+    let key = (DISPUTE_VOTE_PREFIX, dispute_id, juror_address.clone());
+    env.storage().persistent().remove(&key);
+}
+
 /// Store the assigned jurors for a given dispute
 pub fn set_juror_assignments(env: &Env, dispute_id: u64, jurors: &Vec<Address>) {
     let key = (JUROR_ASSIGNMENTS_PREFIX, dispute_id);
@@ -286,6 +687,33 @@ pub fn get_juror_assignments(env: &Env, dispute_id: u64) -> Result<Vec<Address>,
         .ok_or(Error::NotFound)
 }
 
+/// Remove the assigned jurors for a given dispute
+pub fn remove_juror_assignments(env: &Env, dispute_id: u64) {
+    let key = (JUROR_ASSIGNMENTS_PREFIX, dispute_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Store every juror ever seated on a dispute, across all rounds
+pub fn set_past_jurors(env: &Env, dispute_id: u64, jurors: &Vec<Address>) {
+    let key = (PAST_JURORS_PREFIX, dispute_id);
+    env.storage().persistent().set(&key, jurors);
+}
+
+/// Retrieve every juror ever seated on a dispute, across all rounds
+pub fn get_past_jurors(env: &Env, dispute_id: u64) -> Vec<Address> {
+    let key = (PAST_JURORS_PREFIX, dispute_id);
+    env.storage()
+        .persistent()
+        .get::<(&str, u64), Vec<Address>>(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Remove the record of jurors seated on a dispute
+pub fn remove_past_jurors(env: &Env, dispute_id: u64) {
+    let key = (PAST_JURORS_PREFIX, dispute_id);
+    env.storage().persistent().remove(&key);
+}
+
 /// Retrieve the active juror addresses list
 pub fn get_active_jurors(env: &Env) -> Vec<Address> {
     env.storage()
@@ -301,6 +729,8 @@ pub fn set_active_jurors(env: &Env, jurors: &Vec<Address>) {
 
 const PAUSE_STATE_KEY: &str = "pause_state";
 const PENDING_UPGRADE_KEY: &str = "pending_upgrade";
+const VERSION_KEY: &str = "version";
+const REENTRANCY_KEY: &str = "lock";
 
 /// Store the pause state
 pub fn set_pause_state(env: &Env, state: &PauseState) {
@@ -324,6 +754,28 @@ pub fn is_paused(env: &Env) -> bool {
     get_pause_state(env).paused
 }
 
+/// Set or clear a single project's pause flag
+pub fn set_project_paused(env: &Env, project_id: u64, paused: bool) {
+    let key = (PROJECT_PAUSED_PREFIX, project_id);
+    if paused {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether a single project is paused, ignoring the global switch
+pub fn is_project_paused(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(PROJECT_PAUSED_PREFIX, project_id))
+}
+
+/// Check whether a project's operations are halted, globally or for it alone
+pub fn is_paused_for(env: &Env, project_id: u64) -> bool {
+    is_paused(env) || is_project_paused(env, project_id)
+}
+
 /// Store pending upgrade (time-locked)
 pub fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade) {
     env.storage().instance().set(&PENDING_UPGRADE_KEY, pending);
@@ -339,9 +791,31 @@ pub fn clear_pending_upgrade(env: &Env) {
     env.storage().instance().remove(&PENDING_UPGRADE_KEY);
 }
 
-/// Check if an upgrade is scheduled
-pub fn has_pending_upgrade(env: &Env) -> bool {
-    env.storage().instance().has(&PENDING_UPGRADE_KEY)
+/// Take the reentrancy lock, failing if a guarded call is already in progress
+pub fn set_lock(env: &Env) -> Result<(), Error> {
+    if env.storage().instance().has(&REENTRANCY_KEY) {
+        return Err(Error::Reentrancy);
+    }
+    env.storage().instance().set(&REENTRANCY_KEY, &true);
+    Ok(())
+}
+
+/// Release the reentrancy lock
+pub fn clear_lock(env: &Env) {
+    env.storage().instance().remove(&REENTRANCY_KEY);
+}
+
+/// Store the contract version
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&VERSION_KEY, &version);
+}
+
+/// Retrieve the contract version, defaulting to 1
+pub fn get_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get::<&str, u32>(&VERSION_KEY)
+        .unwrap_or(1)
 }
 
 pub fn get_emergency_withdraw_state(env: &Env, project_id: u64) -> EmergencyWithdrawState {
@@ -361,3 +835,42 @@ pub fn set_emergency_withdraw_state(env: &Env, project_id: u64, state: &Emergenc
     let key = (EMERGENCY_WITHDRAW_PREFIX, project_id);
     env.storage().persistent().set(&key, state);
 }
+
+/// Store the reputation contract consulted by escrow
+pub fn set_reputation_contract(env: &Env, contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&REPUTATION_CONTRACT_KEY, contract);
+}
+
+/// Retrieve the reputation contract, if configured
+pub fn get_reputation_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&REPUTATION_CONTRACT_KEY)
+}
+
+/// Store the project-launch contract consulted for backer contributions
+pub fn set_project_launch(env: &Env, contract: &Address) {
+    env.storage().instance().set(&PROJECT_LAUNCH_KEY, contract);
+}
+
+/// Retrieve the project-launch contract, if configured
+pub fn get_project_launch(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&PROJECT_LAUNCH_KEY)
+}
+
+/// Store the minimum creator reputation (enables the gate)
+pub fn set_min_creator_reputation(env: &Env, min_score: i128) {
+    env.storage()
+        .instance()
+        .set(&MIN_CREATOR_REP_KEY, &min_score);
+}
+
+/// Retrieve the minimum creator reputation, if the gate is enabled
+pub fn get_min_creator_reputation(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&MIN_CREATOR_REP_KEY)
+}
+
+/// Remove the minimum creator reputation (disables the gate)
+pub fn clear_min_creator_reputation(env: &Env) {
+    env.storage().instance().remove(&MIN_CREATOR_REP_KEY);
+}
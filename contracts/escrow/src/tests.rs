@@ -1,11 +1,18 @@
 #![cfg(test)]
 
 use crate::{EmergencyWithdrawStatus, EscrowContract, EscrowContractClient};
-use shared::types::{DisputeResolution, MilestoneStatus};
+use badge_only_reputation::MockBadgeOnlyReputation;
+use broken_project_launch::MockBrokenProjectLaunch;
+use ledger_token::{MockLedgerToken, MockLedgerTokenClient};
+use reentrant_token::{MockReentrantToken, MockReentrantTokenClient};
+use shared::errors::Error;
+use shared::types::{
+    BadgeType, DisputeResolution, MilestoneStatus, ReputationProfile, VoteOutcome,
+};
 use soroban_sdk::{
-    contract, contractimpl,
-    testutils::{Address as _, Ledger},
-    Address, BytesN, Env, Vec,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, FromVal, IntoVal, String, Val, Vec,
 };
 
 #[contract]
@@ -19,6 +26,67 @@ impl MockToken {
     }
 }
 
+/// Token that keeps real balances, for asserting which asset a flow charged
+mod ledger_token {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct MockLedgerToken;
+
+    #[contractimpl]
+    impl MockLedgerToken {
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            let from_balance: i128 = env.storage().persistent().get(&from).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&from, &(from_balance - amount));
+            let to_balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+            env.storage().persistent().set(&to, &(to_balance + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&id).unwrap_or(0)
+        }
+    }
+}
+/// Token whose transfer calls back into the escrow contract
+mod reentrant_token {
+    use crate::EscrowContractClient;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct MockReentrantToken;
+
+    #[contractimpl]
+    impl MockReentrantToken {
+        pub fn set_target(env: Env, escrow: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("target"), &escrow);
+        }
+
+        pub fn transfer(env: Env, from: Address, _to: Address, amount: i128) {
+            let escrow: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("target"))
+                .unwrap();
+            let client = EscrowContractClient::new(&env, &escrow);
+            let rejected = client.try_register_as_juror(&from, &amount).is_err();
+            env.storage()
+                .instance()
+                .set(&symbol_short!("rejected"), &rejected);
+        }
+
+        pub fn reentry_rejected(env: Env) -> bool {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("rejected"))
+                .unwrap_or(false)
+        }
+    }
+}
+
 #[contract]
 pub struct MockProfitDist;
 
@@ -29,8 +97,8 @@ impl MockProfitDist {
         _project_id: u64,
         _depositor: Address,
         _amount: i128,
-    ) -> Result<(), shared::errors::Error> {
-        Ok(())
+    ) -> Result<i128, shared::errors::Error> {
+        Ok(0)
     }
 }
 
@@ -48,6 +116,129 @@ impl MockYieldPool {
     }
 }
 
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn set_score(env: Env, user: Address, score: i128) {
+        env.storage().persistent().set(&user, &score);
+    }
+
+    pub fn get_profile(
+        env: Env,
+        user: Address,
+    ) -> Result<ReputationProfile, shared::errors::Error> {
+        let score: i128 = env
+            .storage()
+            .persistent()
+            .get(&user)
+            .ok_or(shared::errors::Error::UserNotReg)?;
+        let badges = env
+            .storage()
+            .persistent()
+            .get(&(user.clone(), symbol_short!("badges")))
+            .unwrap_or(Vec::new(&env));
+        Ok(ReputationProfile {
+            user,
+            score,
+            badges,
+        })
+    }
+
+    pub fn grant_badge(
+        env: Env,
+        _granter: Address,
+        user: Address,
+        badge: BadgeType,
+    ) -> Result<(), shared::errors::Error> {
+        let key = (user, symbol_short!("badges"));
+        let mut badges: Vec<BadgeType> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        if badges.contains(badge) {
+            return Err(shared::errors::Error::BadgeAw);
+        }
+        badges.push_back(badge);
+        env.storage().persistent().set(&key, &badges);
+        Ok(())
+    }
+
+    pub fn grant_score_updates(
+        env: Env,
+        _granter: Address,
+        updates: Vec<(Address, i128)>,
+    ) -> Result<u32, shared::errors::Error> {
+        for (user, delta) in updates.iter() {
+            let score: i128 = env.storage().persistent().get(&user).unwrap_or(0);
+            env.storage().persistent().set(&user, &(score + delta));
+        }
+        Ok(updates.len())
+    }
+
+    pub fn is_banned(env: Env, user: Address) -> bool {
+        let score: Option<i128> = env.storage().persistent().get(&user);
+        matches!(score, Some(score) if score < 10)
+    }
+}
+
+#[contract]
+pub struct MockProjectLaunch;
+
+#[contractimpl]
+impl MockProjectLaunch {
+    pub fn set_contribution(env: Env, project_id: u64, contributor: Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&(project_id, contributor), &amount);
+    }
+
+    pub fn get_user_contribution(env: Env, project_id: u64, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(project_id, contributor))
+            .unwrap_or(0)
+    }
+}
+
+mod broken_project_launch {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Project-launch stand-in whose contribution lookup always traps
+    #[contract]
+    pub struct MockBrokenProjectLaunch;
+
+    #[contractimpl]
+    impl MockBrokenProjectLaunch {
+        pub fn get_user_contribution(_env: Env, _project_id: u64, _contributor: Address) -> i128 {
+            panic!("contribution lookup unavailable")
+        }
+    }
+}
+
+mod badge_only_reputation {
+    use shared::types::BadgeType;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Reputation stand-in deployed only for badges, without `is_banned`
+    #[contract]
+    pub struct MockBadgeOnlyReputation;
+
+    #[contractimpl]
+    impl MockBadgeOnlyReputation {
+        pub fn grant_badge(
+            _env: Env,
+            _granter: Address,
+            _user: Address,
+            _badge: BadgeType,
+        ) -> Result<(), shared::errors::Error> {
+            Ok(())
+        }
+    }
+}
+
 fn create_mock_token(env: &Env) -> Address {
     env.register_contract(None, MockToken)
 }
@@ -74,6 +265,10 @@ fn create_test_env() -> (Env, Address, Address, Address, Vec<Address>) {
     (env, creator, token, validator3, validators)
 }
 
+fn test_uri(env: &Env) -> String {
+    String::from_str(env, "ipfs://proof")
+}
+
 fn create_client(env: &Env) -> EscrowContractClient<'_> {
     EscrowContractClient::new(env, &env.register_contract(None, EscrowContract))
 }
@@ -100,7 +295,17 @@ fn setup_with_admin(
     let client = EscrowContractClient::new(env, &contract_id);
 
     client.initialize_admin(&admin);
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &500);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &500,
+        &0,
+        &0,
+        &0,
+    );
 
     (admin, creator, token, validators, client)
 }
@@ -116,7 +321,17 @@ fn test_initialize_escrow() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &500);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &500,
+        &0,
+        &0,
+        &0,
+    );
 
     let escrow = client.get_escrow(&1);
     assert_eq!(escrow.project_id, 1);
@@ -138,11 +353,71 @@ fn test_initialize_with_insufficient_validators() {
     validators.push_back(Address::generate(&env));
 
     let client = create_client(&env);
-    let result = client.try_initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    let result = client.try_initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     assert!(result.is_err());
 }
 
+#[test]
+fn test_duplicate_validators_rejected() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+
+    let mut duplicated = validators.clone();
+    duplicated.push_back(validators.get(0).unwrap());
+    assert_eq!(
+        client.try_initialize(
+            &1,
+            &creator,
+            &token,
+            &duplicated,
+            &DEFAULT_THRESHOLD,
+            &0,
+            &0,
+            &0,
+            &0,
+        ),
+        Err(Ok(Error::InvInput))
+    );
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(
+        client.try_update_validators(&admin, &1, &duplicated),
+        Err(Ok(Error::InvInput))
+    );
+    assert_eq!(
+        client.try_add_validator(&admin, &1, &validators.get(1).unwrap()),
+        Err(Ok(Error::InvInput))
+    );
+    let result =
+        client.batch_update_validators(&admin, &Vec::from_array(&env, [(1u64, duplicated)]));
+    assert_eq!(result.failed, Vec::from_array(&env, [1u64]));
+    assert_eq!(client.get_escrow(&1).validators, validators);
+}
+
 #[test]
 fn test_initialize_with_invalid_fee() {
     let (env, creator, token, _, validators) = create_test_env();
@@ -156,6 +431,9 @@ fn test_initialize_with_invalid_fee() {
         &validators,
         &DEFAULT_THRESHOLD,
         &10001,
+        &0,
+        &0,
+        &0,
     );
     assert!(result.is_err(), "fee above 100% should be rejected");
 }
@@ -166,9 +444,29 @@ fn test_initialize_duplicate_escrow() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
-    let result = client.try_initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    let result = client.try_initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     assert!(result.is_err());
 }
 
@@ -178,7 +476,17 @@ fn test_deposit_funds() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     let deposit_amount: i128 = 1000;
     let result = client.try_deposit(&1, &deposit_amount);
@@ -189,13 +497,77 @@ fn test_deposit_funds() {
     assert_eq!(escrow.total_deposited, deposit_amount);
 }
 
+#[test]
+fn test_initialize_with_deposit_funds_escrow_in_one_call() {
+    let (env, creator, _, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+    let token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+    ledger.transfer(&Address::generate(&env), &creator, &1000);
+
+    client.initialize_with_deposit(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+        &1000,
+    );
+
+    let escrow = client.get_escrow(&1);
+    assert_eq!(escrow.creator, creator);
+    assert_eq!(escrow.total_deposited, 1000);
+    // The reported deposit is backed by tokens pulled from the creator
+    assert_eq!(ledger.balance(&client.address), 1000);
+    assert_eq!(ledger.balance(&creator), 0);
+    let events = env.events().all();
+    let emitted = |topic: Vec<Val>| events.iter().any(|(_, topics, _)| topics == topic);
+    assert!(emitted(
+        (shared::events::ESCROW_INITIALIZED,).into_val(&env)
+    ));
+    assert!(emitted((shared::events::FUNDS_LOCKED,).into_val(&env)));
+
+    // A bad deposit leaves no escrow behind
+    assert_eq!(
+        client.try_initialize_with_deposit(
+            &2,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &0,
+            &0,
+            &0,
+            &0,
+            &0,
+        ),
+        Err(Ok(Error::InvInput))
+    );
+    assert!(client.try_get_escrow(&2).is_err());
+}
+
 #[test]
 fn test_deposit_invalid_amount() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     let result = client.try_deposit(&1, &0);
     assert!(result.is_err());
@@ -204,13 +576,58 @@ fn test_deposit_invalid_amount() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_deposit_rejected_after_deadline() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &2000,
+        &0,
+        &0,
+    );
+
+    client.deposit(&1, &500);
+    env.ledger().set_timestamp(2000);
+    client.deposit(&1, &300);
+    assert_eq!(client.get_escrow(&1).total_deposited, 800);
+
+    env.ledger().set_timestamp(2001);
+    assert_eq!(
+        client.try_deposit(&1, &100),
+        Err(Ok(Error::DepositWindowClosed))
+    );
+    assert_eq!(
+        client.try_deposit_token(&1, &Address::generate(&env), &100),
+        Err(Ok(Error::DepositWindowClosed))
+    );
+    assert_eq!(client.get_escrow(&1).total_deposited, 800);
+}
+
 #[test]
 fn test_create_milestone() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
 
     env.mock_all_auths();
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     client.deposit(&1, &1000);
 
     let description_hash = BytesN::from_array(&env, &[1u8; 32]);
@@ -230,7 +647,17 @@ fn test_create_milestone_exceeds_escrow() {
     let client = create_client(&env);
 
     env.mock_all_auths();
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     client.deposit(&1, &500);
 
     let description_hash = BytesN::from_array(&env, &[2u8; 32]);
@@ -245,7 +672,17 @@ fn test_create_multiple_milestones() {
     let client = create_client(&env);
 
     env.mock_all_auths();
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     client.deposit(&1, &3000);
 
     let desc1 = BytesN::from_array(&env, &[1u8; 32]);
@@ -265,91 +702,280 @@ fn test_create_multiple_milestones() {
 }
 
 #[test]
-fn test_submit_milestone() {
+fn test_batch_create_milestones() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
 
     env.mock_all_auths();
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
-    client.deposit(&1, &1000);
-
-    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-    client.create_milestone(&1, &description_hash, &500);
-
-    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &3000);
 
-    let milestone = client.get_milestone(&1, &0);
-    assert_eq!(milestone.status, MilestoneStatus::Submitted);
-    assert_eq!(milestone.proof_hash, proof_hash);
+    let desc = |byte: u8| BytesN::from_array(&env, &[byte; 32]);
+    let roadmap = Vec::from_array(&env, [(desc(1), 500), (desc(2), 1000), (desc(3), 700)]);
+    let result = client.batch_create_milestones(&1, &roadmap);
+    assert_eq!(result.succeeded, 3);
+    assert!(result.failed.is_empty());
+    assert_eq!(client.get_milestone(&1, &2).amount, 700);
+    assert_eq!(client.get_milestone(&1, &2).description_hash, desc(3));
+    let created = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == (shared::events::MILESTONE_CREATED,).into_val(&env))
+        .count();
+    assert_eq!(created, 3);
+
+    // 800 of the deposit is left: the first fits, the second would exceed it
+    let overflow = Vec::from_array(&env, [(desc(4), 600), (desc(5), 300)]);
+    let result = client.batch_create_milestones(&1, &overflow);
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(result.failed, Vec::from_array(&env, [1u64]));
+    assert_eq!(client.get_total_milestone_amount(&1), 2800);
+    assert!(client.try_get_milestone(&1, &4).is_err());
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=shared::constants::MAX_BATCH_SIZE {
+        too_many.push_back((desc(6), 10));
+    }
+    assert_eq!(
+        client.try_batch_create_milestones(&1, &too_many),
+        Err(Ok(Error::InvInput))
+    );
 }
 
 #[test]
-fn test_submit_milestone_invalid_status() {
+fn test_create_milestone_limit() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
-    env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    env.mock_all_auths();
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     client.deposit(&1, &1000);
 
     let description_hash = BytesN::from_array(&env, &[1u8; 32]);
-    client.create_milestone(&1, &description_hash, &500);
-
-    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
-
-    let proof_hash2 = BytesN::from_array(&env, &[10u8; 32]);
-    let result = client.try_submit_milestone(&1, &0, &proof_hash2);
+    for _ in 0..shared::MAX_MILESTONES_PER_PROJECT {
+        client.create_milestone(&1, &description_hash, &10);
+    }
 
-    assert!(result.is_err());
+    let result = client.try_create_milestone(&1, &description_hash, &10);
+    assert_eq!(result, Err(Ok(Error::MstoneLimit)));
 }
 
 #[test]
-fn test_get_available_balance() {
-    let (env, creator, token, _, validators) = create_test_env();
-    let client = create_client(&env);
+fn test_milestone_below_minimum_amount_rejected() {
+    let env = Env::default();
     env.mock_all_auths();
-
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
-
+    let (admin, _, _, _, client) = setup_with_admin(&env);
     client.deposit(&1, &1000);
-    let balance = client.get_available_balance(&1);
-    assert_eq!(balance, 1000);
-
-    client.deposit(&1, &500);
-    let balance = client.get_available_balance(&1);
-    assert_eq!(balance, 1500);
-}
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(
+        client.get_min_milestone_amount(),
+        shared::MIN_MILESTONE_AMOUNT
+    );
 
-#[test]
-fn test_escrow_not_found() {
-    let env = Env::default();
-    let client = create_client(&env);
+    assert_eq!(
+        client.try_create_milestone(&1, &description_hash, &1),
+        Err(Ok(Error::InvInput))
+    );
+    client.create_milestone(&1, &description_hash, &shared::MIN_MILESTONE_AMOUNT);
 
-    let result = client.try_get_escrow(&999);
-    assert!(result.is_err());
+    // The admin can raise the floor for the deployment
+    assert_eq!(
+        client.try_configure_min_milestone_amount(&admin, &0),
+        Err(Ok(Error::InvInput))
+    );
+    client.configure_min_milestone_amount(&admin, &100);
+    assert_eq!(
+        client.try_create_milestone(&1, &description_hash, &99),
+        Err(Ok(Error::InvInput))
+    );
+    client.create_milestone(&1, &description_hash, &100);
+    assert_eq!(client.get_milestone(&1, &1).amount, 100);
 }
 
 #[test]
-fn test_milestone_not_found() {
-    let (env, creator, token, _, validators) = create_test_env();
-    let client = create_client(&env);
+fn test_admin_overrides_milestone_limit() {
+    let env = Env::default();
     env.mock_all_auths();
+    let (admin, _, _, _, client) = setup_with_admin(&env);
+    client.deposit(&1, &1000);
+    assert_eq!(
+        client.get_milestone_limit(),
+        shared::MAX_MILESTONES_PER_PROJECT
+    );
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    assert_eq!(
+        client.try_configure_milestone_limit(&admin, &0),
+        Err(Ok(Error::InvInput))
+    );
+    client.configure_milestone_limit(&admin, &3);
 
-    let result = client.try_get_milestone(&1, &999);
-    assert!(result.is_err());
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+    for _ in 0..3 {
+        client.create_milestone(&1, &description_hash, &10);
+    }
+    assert_eq!(
+        client.try_create_milestone(&1, &description_hash, &10),
+        Err(Ok(Error::MstoneLimit))
+    );
 }
 
 #[test]
-fn test_milestone_status_transitions() {
+fn test_submit_milestone() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+
+    env.mock_all_auths();
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.create_milestone(&1, &description_hash, &500);
+
+    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
+
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.status, MilestoneStatus::Submitted);
+    assert_eq!(milestone.proof_hash, proof_hash);
+}
+
+#[test]
+fn test_submit_milestone_invalid_status() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.create_milestone(&1, &description_hash, &500);
+
+    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
+
+    let proof_hash2 = BytesN::from_array(&env, &[10u8; 32]);
+    let result = client.try_submit_milestone(&1, &0, &proof_hash2, &test_uri(&env));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_get_available_balance() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+
+    client.deposit(&1, &1000);
+    let balance = client.get_available_balance(&1, &token);
+    assert_eq!(balance, 1000);
+
+    client.deposit(&1, &500);
+    let balance = client.get_available_balance(&1, &token);
+    assert_eq!(balance, 1500);
+}
+
+#[test]
+fn test_escrow_not_found() {
+    let env = Env::default();
+    let client = create_client(&env);
+
+    let result = client.try_get_escrow(&999);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_milestone_not_found() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+
+    let result = client.try_get_milestone(&1, &999);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_milestone_status_transitions() {
     let (env, creator, token, _, validators) = create_test_env();
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
     client.deposit(&1, &1000);
 
     let description_hash = BytesN::from_array(&env, &[1u8; 32]);
@@ -361,7 +987,7 @@ fn test_milestone_status_transitions() {
     assert_eq!(milestone.rejection_count, 0);
 
     let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
 
     let milestone = client.get_milestone(&1, &0);
     assert_eq!(milestone.status, MilestoneStatus::Submitted);
@@ -374,7 +1000,17 @@ fn test_deposit_updates_correctly() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     client.deposit(&1, &500);
     assert_eq!(client.get_escrow(&1).total_deposited, 500);
@@ -405,7 +1041,17 @@ fn test_multiple_projects_isolated() {
 
     let client = create_client(&env);
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     let escrow1 = client.get_escrow(&1);
     assert_eq!(escrow1.project_id, 1);
@@ -440,7 +1086,7 @@ fn test_initialize_with_low_threshold() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    let result = client.try_initialize(&1, &creator, &token, &validators, &5000, &0);
+    let result = client.try_initialize(&1, &creator, &token, &validators, &5000, &0, &0, &0, &0);
     assert!(result.is_err(), "threshold below 51% should be rejected");
 }
 
@@ -450,7 +1096,7 @@ fn test_initialize_with_threshold_above_100() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    let result = client.try_initialize(&1, &creator, &token, &validators, &10100, &0);
+    let result = client.try_initialize(&1, &creator, &token, &validators, &10100, &0, &0, &0, &0);
     assert!(result.is_err(), "threshold above 100% should be rejected");
 }
 
@@ -460,7 +1106,7 @@ fn test_minimum_valid_threshold_accepted() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    let result = client.try_initialize(&1, &creator, &token, &validators, &5100, &0);
+    let result = client.try_initialize(&1, &creator, &token, &validators, &5100, &0, &0, &0, &0);
     assert!(result.is_ok(), "5100 basis points (51%) should be accepted");
 }
 
@@ -470,7 +1116,7 @@ fn test_maximum_valid_threshold_accepted() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    let result = client.try_initialize(&1, &creator, &token, &validators, &10000, &0);
+    let result = client.try_initialize(&1, &creator, &token, &validators, &10000, &0, &0, &0, &0);
     assert!(
         result.is_ok(),
         "10000 basis points (100%) should be accepted"
@@ -493,8 +1139,8 @@ fn test_different_projects_have_independent_thresholds() {
 
     let client = create_client(&env);
 
-    client.initialize(&1, &creator, &token, &validators, &6700, &0);
-    client.initialize(&2, &creator, &token, &validators, &10000, &1000);
+    client.initialize(&1, &creator, &token, &validators, &6700, &0, &0, &0, &0);
+    client.initialize(&2, &creator, &token, &validators, &10000, &1000, &0, &0, &0);
 
     assert_eq!(client.get_escrow(&1).approval_threshold, 6700);
     assert_eq!(client.get_escrow(&2).approval_threshold, 10000);
@@ -512,7 +1158,7 @@ fn test_dispute_happy_path() {
     client.configure_dispute_token(&juror_token);
 
     // Use 10000 (100%) threshold — requires ALL 3 validators to approve
-    client.initialize(&1, &creator, &token, &validators, &10000, &0);
+    client.initialize(&1, &creator, &token, &validators, &10000, &0, &0, &0, &0);
 
     let v1 = validators.get(0).unwrap();
     let v2 = validators.get(1).unwrap();
@@ -530,10 +1176,13 @@ fn test_dispute_happy_path() {
     client.create_milestone(&1, &description_hash, &500);
 
     let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
 
     // With 100% threshold, 1 vote is not enough
-    client.vote_milestone(&1, &0, &v1, &true);
+    assert_eq!(
+        client.vote_milestone(&1, &0, &v1, &true),
+        VoteOutcome::Recorded
+    );
     assert_eq!(
         client.get_milestone(&1, &0).status,
         MilestoneStatus::Submitted,
@@ -541,7 +1190,10 @@ fn test_dispute_happy_path() {
     );
 
     // With 100% threshold, 2 votes are still not enough
-    client.vote_milestone(&1, &0, &v2, &true);
+    assert_eq!(
+        client.vote_milestone(&1, &0, &v2, &true),
+        VoteOutcome::Recorded
+    );
     assert_eq!(
         client.get_milestone(&1, &0).status,
         MilestoneStatus::Submitted,
@@ -549,7 +1201,10 @@ fn test_dispute_happy_path() {
     );
 
     // With 100% threshold, all 3 votes trigger approval
-    client.vote_milestone(&1, &0, &v3, &true);
+    assert_eq!(
+        client.vote_milestone(&1, &0, &v3, &true),
+        VoteOutcome::Approved
+    );
     assert_eq!(
         client.get_milestone(&1, &0).status,
         MilestoneStatus::Approved,
@@ -563,7 +1218,17 @@ fn test_dynamic_quorum_based_on_milestone_amount() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     client.deposit(&1, &1000);
     let description_hash = BytesN::from_array(&env, &[1u8; 32]);
@@ -571,7 +1236,7 @@ fn test_dynamic_quorum_based_on_milestone_amount() {
     // Low-value milestone (requires 2 signatures)
     client.create_milestone(&1, &description_hash, &500);
     let proof_hash = BytesN::from_array(&env, &[11u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
 
     client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
     assert_eq!(
@@ -591,7 +1256,7 @@ fn test_dynamic_quorum_based_on_milestone_amount() {
     client.deposit(&1, &300_000);
     client.create_milestone(&1, &description_hash, &200_000);
     let proof_hash_high = BytesN::from_array(&env, &[12u8; 32]);
-    client.submit_milestone(&1, &1, &proof_hash_high);
+    client.submit_milestone(&1, &1, &proof_hash_high, &test_uri(&env));
 
     client.vote_milestone(&1, &1, &validators.get(0).unwrap(), &true);
     client.vote_milestone(&1, &1, &validators.get(1).unwrap(), &true);
@@ -621,7 +1286,17 @@ fn test_dispute_appeals_path() {
     client.configure_dispute_token(&juror_token);
 
     // Use DEFAULT_THRESHOLD (67%) — with 3 validators, 2 votes meets the threshold
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &0);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
 
     let v1 = validators.get(0).unwrap();
     let v2 = validators.get(1).unwrap();
@@ -638,14 +1313,19 @@ fn test_dispute_appeals_path() {
     client.create_milestone(&1, &description_hash, &500);
 
     let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
 
     // Two rejections to trigger dispute
-    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false);
-    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false);
+    assert_eq!(
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false),
+        VoteOutcome::Recorded
+    );
+    assert_eq!(
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false),
+        VoteOutcome::Rejected
+    );
 
-    let project_contract = Address::generate(&env);
-    let dispute_id = client.initiate_dispute(&1, &0, &creator, &project_contract);
+    let dispute_id = client.initiate_dispute(&1, &0, &creator);
 
     client.select_jury(&dispute_id);
 
@@ -662,7 +1342,7 @@ fn test_dispute_appeals_path() {
         b.append(&salt);
         let h = env.crypto().sha256(&b);
 
-        client.commit_vote(&dispute_id, &juror_addr, &h.into());
+        client.commit_vote(&dispute_id, &juror_addr, &juror_addr, &h.into());
     }
 
     env.ledger()
@@ -673,6 +1353,7 @@ fn test_dispute_appeals_path() {
         client.reveal_vote(
             &dispute_id,
             &juror_addr,
+            &juror_addr,
             &DisputeResolution::RelFunds,
             &0,
             &salt,
@@ -680,31 +1361,183 @@ fn test_dispute_appeals_path() {
     }
 
     env.ledger()
-        .set_timestamp(env.ledger().timestamp() + 172801);
+        .set_timestamp(env.ledger().timestamp() + 172801 + shared::REVEAL_GRACE_PERIOD);
     client.tally_votes(&dispute_id);
 
     // File appeal
     let appellant = Address::generate(&env);
     client.file_appeal(&dispute_id, &appellant);
 
-    // Re-submit milestone so validators can vote on it again after appeal
-    client.submit_milestone(&1, &0, &proof_hash);
+    // The appeal opens a new jury round; the milestone is not handed back to validators
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::Voting);
+    assert_eq!(dispute.appeal_count, 1);
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Rejected,
+        "appeal should leave the disputed milestone untouched"
+    );
+    assert!(client
+        .try_submit_milestone(&1, &0, &proof_hash, &test_uri(&env))
+        .is_err());
+    assert!(client.try_vote_milestone(&1, &0, &v1, &true).is_err());
+    assert!(client.try_vote_milestone(&1, &0, &v2, &true).is_err());
+
+    // The appeal panel is larger and shares no one with the original panel
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    assert_eq!(appeal_jurors.len(), shared::APPEAL_JURY_SIZE);
+    for juror in appeal_jurors.iter() {
+        assert!(!assigned_jurors.contains(&juror));
+    }
+}
+
+fn commit_all(
+    client: &EscrowContractClient,
+    dispute_id: u64,
+    jurors: &Vec<Address>,
+    vote: DisputeResolution,
+    salt: &soroban_sdk::Bytes,
+) {
+    let commitment = client.compute_commitment(&vote, &0, salt);
+    for juror in jurors.iter() {
+        client.commit_vote(&dispute_id, &juror, &juror, &commitment);
+    }
+}
+
+fn reveal_all(
+    client: &EscrowContractClient,
+    dispute_id: u64,
+    jurors: &Vec<Address>,
+    vote: DisputeResolution,
+    salt: &soroban_sdk::Bytes,
+) {
+    for juror in jurors.iter() {
+        client.reveal_vote(&dispute_id, &juror, &juror, &vote, &0, salt);
+    }
+}
+
+#[test]
+fn test_appeal_round_has_independent_commit_reveal() {
+    let env = Env::default();
+    let (client, creator, dispute_id, original_jurors) = setup_voting_dispute(&env, 20);
+    let salt = test_salt(&env);
+    // A ledger juror token makes the slash compensation observable
+    let juror_token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &juror_token);
+    client.configure_dispute_token(&juror_token);
+
+    // Original round resolves in favour of refunding backers over one dissenter
+    let (_, dissenter) = tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &original_jurors,
+        DisputeResolution::RefBackers,
+    );
+    assert_eq!(
+        client.get_dispute(&dispute_id).resolution,
+        DisputeResolution::RefBackers
+    );
+
+    // The refund side's share of the slash is only reserved, not credited yet
+    let compensation = juror_info(&env, &client, &dissenter).total_slashed
+        * shared::SLASH_TO_WINNER_BPS as i128
+        / 10_000;
+    assert!(compensation > 0);
+    assert_eq!(
+        client.get_dispute(&dispute_id).pending_compensation,
+        compensation
+    );
+    assert_eq!(client.get_available_balance(&1, &juror_token), 0);
+
+    // Appeal: a fresh commit window opens for the new panel only
+    client.file_appeal(&dispute_id, &Address::generate(&env));
+    let appeal_start = env.ledger().timestamp();
+    assert_eq!(client.get_dispute(&dispute_id).created_at, appeal_start);
+
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    let original = original_jurors.get(0).unwrap();
+    let late_commit = client.compute_commitment(&DisputeResolution::RefBackers, &0, &salt);
+    assert_eq!(
+        client.try_commit_vote(&dispute_id, &original, &original, &late_commit),
+        Err(Ok(Error::NotJuror))
+    );
+
+    commit_all(
+        &client,
+        dispute_id,
+        &appeal_jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+
+    // Reveals only open after the appeal's own commit window
+    let first = appeal_jurors.get(0).unwrap();
+    assert_eq!(
+        client.try_reveal_vote(
+            &dispute_id,
+            &first,
+            &first,
+            &DisputeResolution::RelFunds,
+            &0,
+            &salt
+        ),
+        Err(Ok(Error::RevealNA))
+    );
+    assert_eq!(client.try_tally_votes(&dispute_id), Err(Ok(Error::VoteNA)));
+
+    env.ledger()
+        .set_timestamp(appeal_start + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        &client,
+        dispute_id,
+        &appeal_jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+
+    // Tally waits for the appeal's reveal window, then counts only the appeal panel
+    assert_eq!(client.try_tally_votes(&dispute_id), Err(Ok(Error::VoteNA)));
+    env.ledger().set_timestamp(
+        appeal_start
+            + shared::VOTING_COMMIT_PERIOD
+            + shared::VOTING_REVEAL_PERIOD
+            + shared::REVEAL_GRACE_PERIOD
+            + 1,
+    );
+    client.tally_votes(&dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::Resolved);
+    assert_eq!(dispute.resolution, DisputeResolution::RelFunds);
+    assert_eq!(dispute.appeal_count, 1);
 
-    // With 67% threshold and 3 validators, 1 vote (33%) is not enough
-    client.vote_milestone(&1, &0, &v1, &true);
+    // The tally only records the outcome; funds move in execute_resolution
     assert_eq!(
         client.get_milestone(&1, &0).status,
-        MilestoneStatus::Submitted,
-        "one approval should not meet the 67% threshold with 3 validators"
+        MilestoneStatus::Rejected
+    );
+    assert_eq!(client.get_escrow(&1).released_amount, 0);
+    assert_eq!(ledger.balance(&creator), 0);
+    assert_eq!(
+        client.try_execute_resolution(&dispute_id),
+        Err(Ok(Error::AppealWinCl))
     );
 
-    // With 67% threshold and 3 validators, 2 votes (67%) meets the threshold
-    client.vote_milestone(&1, &0, &v2, &true);
+    execute_after_appeal_window(&env, &client, dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        shared::types::DisputeStatus::FinalResolved
+    );
     assert_eq!(
         client.get_milestone(&1, &0).status,
-        MilestoneStatus::Approved,
-        "two approvals should meet the 67% threshold with 3 validators"
+        MilestoneStatus::Approved
     );
+    assert_eq!(client.get_escrow(&1).released_amount, 500);
+
+    // The appeal flipped the outcome, so the reserved share goes to the creator
+    assert_eq!(ledger.balance(&creator), compensation);
+    assert_eq!(client.get_available_balance(&1, &juror_token), 0);
 }
 
 // ====== NEW tests for Emergency Pause/Resume ======
@@ -772,7 +1605,7 @@ fn test_pause_blocks_submit_milestone() {
     client.pause(&admin);
 
     let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    let result = client.try_submit_milestone(&1, &0, &proof_hash);
+    let result = client.try_submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
     assert!(
         result.is_err(),
         "submit_milestone should be blocked when paused"
@@ -790,7 +1623,7 @@ fn test_pause_blocks_vote_milestone() {
     let description_hash = BytesN::from_array(&env, &[1u8; 32]);
     client.create_milestone(&1, &description_hash, &500);
     let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
-    client.submit_milestone(&1, &0, &proof_hash);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
     client.pause(&admin);
 
     let voter = validators.get(0).unwrap();
@@ -848,6 +1681,26 @@ fn test_operations_work_after_resume() {
     assert!(result.is_ok(), "deposit should work after resume");
 }
 
+#[test]
+fn test_resume_eta_counts_down() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+    let (admin, _, _, _, client) = setup_with_admin(&env);
+    assert_eq!(client.get_resume_eta(), 0);
+
+    client.pause(&admin);
+    assert_eq!(client.get_resume_eta(), shared::RESUME_TIME_DELAY);
+
+    env.ledger().set_timestamp(1000 + 3600);
+    assert_eq!(client.get_resume_eta(), shared::RESUME_TIME_DELAY - 3600);
+
+    env.ledger()
+        .set_timestamp(1000 + shared::RESUME_TIME_DELAY + 1);
+    assert_eq!(client.get_resume_eta(), 0);
+    client.resume(&admin);
+}
+
 #[test]
 fn test_only_admin_can_pause() {
     let env = Env::default();
@@ -910,6 +1763,28 @@ fn test_execute_upgrade_too_early_fails() {
     assert!(result.is_err(), "execute_upgrade should fail before 48h");
 }
 
+#[test]
+fn test_upgrade_eta_counts_down() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+    let (admin, _, _, _, client) = setup_with_admin(&env);
+    assert_eq!(client.get_upgrade_eta(), 0);
+
+    client.schedule_upgrade(&admin, &BytesN::from_array(&env, &[42u8; 32]));
+    assert_eq!(client.get_upgrade_eta(), shared::UPGRADE_TIME_LOCK_SECS);
+
+    env.ledger().set_timestamp(1000 + 3600);
+    assert_eq!(
+        client.get_upgrade_eta(),
+        shared::UPGRADE_TIME_LOCK_SECS - 3600
+    );
+
+    env.ledger()
+        .set_timestamp(1000 + shared::UPGRADE_TIME_LOCK_SECS + 1);
+    assert_eq!(client.get_upgrade_eta(), 0);
+}
+
 #[test]
 fn test_execute_upgrade_without_pause_fails() {
     let env = Env::default();
@@ -944,6 +1819,21 @@ fn test_cancel_upgrade_clears_pending() {
     assert!(client.get_pending_upgrade().is_none());
 }
 
+#[test]
+fn test_version_bumped_by_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, _, _, client) = setup_with_admin(&env);
+    assert_eq!(client.get_version(), 1);
+
+    // Stand-in for execute_upgrade, which bumps the version once the new wasm is live
+    env.as_contract(&client.address, || {
+        let version = <EscrowContract as shared::upgradeable::Upgradeable>::version(&env);
+        <EscrowContract as shared::upgradeable::Upgradeable>::set_version(&env, version + 1);
+    });
+    assert_eq!(client.get_version(), 2);
+}
+
 #[test]
 fn test_only_admin_can_schedule_upgrade() {
     let env = Env::default();
@@ -966,7 +1856,17 @@ fn test_claim_yield_happy_path() {
     let profit_dist_id = env.register_contract(None, MockProfitDist);
 
     // 5% management fee
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &500);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &500,
+        &0,
+        &0,
+        &0,
+    );
 
     // Initial deposit of 1000
     client.deposit(&1, &1000);
@@ -982,7 +1882,17 @@ fn test_claim_yield_no_yield() {
     let client = create_client(&env);
     env.mock_all_auths();
 
-    client.initialize(&1, &creator, &token, &validators, &DEFAULT_THRESHOLD, &500);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &500,
+        &0,
+        &0,
+        &0,
+    );
     let profit_dist_id = Address::generate(&env);
 
     // No tokens in contract, should fail
@@ -1039,3 +1949,3246 @@ fn test_emergency_withdraw_rejects_non_validator_approval() {
         "only escrow validators may co-sign emergency rescue"
     );
 }
+
+#[test]
+fn test_project_pause_leaves_other_projects_running() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+    let (admin, creator, token, validators, client) = setup_with_admin(&env);
+    client.initialize(
+        &2,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.deposit(&2, &1000);
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.pause_project(&admin, &1);
+    assert!(client.is_project_paused(&1));
+    assert!(!client.is_project_paused(&2));
+    assert_eq!(client.try_deposit(&1, &100), Err(Ok(Error::Paused)));
+    assert_eq!(
+        client.try_create_milestone(&1, &description_hash, &500),
+        Err(Ok(Error::Paused))
+    );
+    // A pause is reported as such, ahead of any per-milestone validation
+    assert_eq!(
+        client.try_create_milestone(&1, &description_hash, &0),
+        Err(Ok(Error::Paused))
+    );
+    let roadmap = Vec::from_array(&env, [(description_hash.clone(), 500)]);
+    assert_eq!(
+        client.try_batch_create_milestones(&1, &roadmap),
+        Err(Ok(Error::Paused))
+    );
+    client.deposit(&2, &100);
+    client.create_milestone(&2, &description_hash, &500);
+    client.submit_milestone(
+        &2,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    client.vote_milestone(&2, &0, &validators.get(0).unwrap(), &true);
+
+    client.resume_project(&admin, &1);
+    client.create_milestone(&1, &description_hash, &500);
+    assert_eq!(
+        client.try_resume_project(&admin, &1),
+        Err(Ok(Error::InvStatus))
+    );
+
+    // The global pause remains the master switch
+    client.pause(&admin);
+    assert!(client.is_project_paused(&2));
+    assert_eq!(client.try_deposit(&2, &100), Err(Ok(Error::Paused)));
+}
+
+// ── Minimum-reputation gate ──
+
+#[test]
+fn test_reputation_gate_disabled_by_default() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    // No reputation contract configured: any creator may initialize
+    let result = client.try_initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_reputation_gate_blocks_low_reputation_creator() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize_admin(&admin);
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    reputation.set_score(&creator, &(shared::MIN_CREATOR_REPUTATION - 1));
+
+    client.configure_reputation_gate(&admin, &reputation_id, &shared::MIN_CREATOR_REPUTATION);
+
+    let result = client.try_initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(result, Err(Ok(Error::RepTooLow)));
+
+    // Unregistered creators are blocked too
+    let stranger = Address::generate(&env);
+    let result = client.try_initialize(
+        &2,
+        &stranger,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(result, Err(Ok(Error::RepTooLow)));
+
+    // Disabling the gate restores open creation
+    client.disable_reputation_gate(&admin);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+}
+
+#[test]
+fn test_reputation_gate_allows_sufficient_reputation() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize_admin(&admin);
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    reputation.set_score(&creator, &shared::MIN_CREATOR_REPUTATION);
+
+    client.configure_reputation_gate(&admin, &reputation_id, &shared::MIN_CREATOR_REPUTATION);
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(client.get_escrow(&1).creator, creator);
+}
+
+// ── MilestoneAchiever badge ──
+
+#[test]
+fn test_milestone_approval_awards_badge_once() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize_admin(&admin);
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    reputation.set_score(&creator, &shared::REPUTATION_START);
+    client.configure_reputation_contract(&admin, &reputation_id);
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+
+    let description_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+    for milestone_id in 0..2u64 {
+        client.create_milestone(&1, &description_hash, &400);
+        client.submit_milestone(&1, &milestone_id, &proof_hash, &test_uri(&env));
+        client.vote_milestone(&1, &milestone_id, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&1, &milestone_id, &validators.get(1).unwrap(), &true);
+        assert_eq!(
+            client.get_milestone(&1, &milestone_id).status,
+            MilestoneStatus::Approved
+        );
+    }
+
+    // Second approval hit the already-awarded path without failing
+    let badges = reputation.get_profile(&creator).badges;
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges.get(0).unwrap(), BadgeType::MilestoneAchiever);
+}
+
+// ── Dispute helpers ──
+
+/// Escrow with a rejected milestone under dispute and a jury in the commit phase.
+/// Registers exactly `num_jurors` jurors.
+fn setup_pending_dispute(
+    env: &Env,
+    num_jurors: u32,
+) -> (EscrowContractClient<'_>, Address, Address, u64) {
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+
+    let client = create_client(env);
+    let admin = Address::generate(env);
+    client.initialize_admin(&admin);
+    client.configure_dispute_token(&create_mock_token(env));
+
+    let creator = Address::generate(env);
+    let token = create_mock_token(env);
+    let mut validators = Vec::new(env);
+    for _ in 0..3 {
+        validators.push_back(Address::generate(env));
+    }
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+
+    for _ in 0..num_jurors {
+        client.register_as_juror(&Address::generate(env), &500_0000000);
+    }
+
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(env, &[1u8; 32]), &500);
+    client.submit_milestone(&1, &0, &BytesN::from_array(env, &[9u8; 32]), &test_uri(env));
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false);
+
+    let project_launch = env.register_contract(None, MockProjectLaunch);
+    client.configure_project_launch(&admin, &project_launch);
+    let dispute_id = client.initiate_dispute(&1, &0, &creator);
+
+    (client, admin, creator, dispute_id)
+}
+
+fn setup_voting_dispute(
+    env: &Env,
+    num_jurors: u32,
+) -> (EscrowContractClient<'_>, Address, u64, Vec<Address>) {
+    let (client, _, creator, dispute_id) = setup_pending_dispute(env, num_jurors);
+    client.select_jury(&dispute_id);
+    let jurors = client.get_juror_assignments(&dispute_id);
+
+    (client, creator, dispute_id, jurors)
+}
+
+fn test_salt(env: &Env) -> soroban_sdk::Bytes {
+    soroban_sdk::Bytes::from_array(env, &[123u8; 32])
+}
+
+// ── Commitment preimage ──
+
+#[test]
+fn test_compute_commitment_matches_preimage() {
+    let env = Env::default();
+    let client = create_client(&env);
+    let salt = test_salt(&env);
+
+    let cases = [
+        (DisputeResolution::RelFunds, 0u32, 0u8),
+        (DisputeResolution::RefBackers, 0u32, 1u8),
+        (DisputeResolution::PartRel, 4000u32, 2u8),
+    ];
+    for (vote, payload, variant) in cases {
+        let mut b = soroban_sdk::Bytes::new(&env);
+        b.push_back(variant);
+        if vote == DisputeResolution::PartRel {
+            for byte in payload.to_be_bytes() {
+                b.push_back(byte);
+            }
+        }
+        b.append(&salt);
+        let expected: BytesN<32> = env.crypto().sha256(&b).into();
+
+        assert_eq!(client.compute_commitment(&vote, &payload, &salt), expected);
+    }
+
+    let result = client.try_compute_commitment(&DisputeResolution::NoRes, &0, &salt);
+    assert_eq!(result, Err(Ok(Error::InvInput)));
+}
+
+#[test]
+fn test_compute_commitment_accepted_by_reveal() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let salt = test_salt(&env);
+
+    let votes = [
+        (DisputeResolution::RelFunds, 0u32),
+        (DisputeResolution::RefBackers, 0u32),
+        (DisputeResolution::PartRel, 2500u32),
+    ];
+    for (i, (vote, payload)) in votes.iter().enumerate() {
+        let juror = jurors.get(i as u32).unwrap();
+        let commitment = client.compute_commitment(vote, payload, &salt);
+        client.commit_vote(&dispute_id, &juror, &juror, &commitment);
+    }
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+
+    for (i, (vote, payload)) in votes.iter().enumerate() {
+        let juror = jurors.get(i as u32).unwrap();
+        client.reveal_vote(&dispute_id, &juror, &juror, vote, payload, &salt);
+    }
+}
+
+// ── Reputation bans ──
+
+#[test]
+fn test_banned_user_cannot_register_as_juror() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.configure_dispute_token(&create_mock_token(&env));
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    client.configure_reputation_contract(&admin, &reputation_id);
+
+    let banned = Address::generate(&env);
+    reputation.set_score(&banned, &0);
+    let result = client.try_register_as_juror(&banned, &500_0000000);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let trusted = Address::generate(&env);
+    reputation.set_score(&trusted, &shared::REPUTATION_START);
+    client.register_as_juror(&trusted, &500_0000000);
+}
+
+#[test]
+fn test_ban_check_fails_open_without_is_banned() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.configure_dispute_token(&create_mock_token(&env));
+
+    let reputation_id = env.register_contract(None, MockBadgeOnlyReputation);
+    client.configure_reputation_contract(&admin, &reputation_id);
+
+    // The contract cannot report bans, so nobody is treated as banned
+    let juror = Address::generate(&env);
+    assert_eq!(
+        client.try_register_as_juror(&juror, &500_0000000),
+        Ok(Ok(()))
+    );
+}
+
+/// Tally a dispute where every juror but the last votes `vote` and the last dissents
+///
+/// Returns the majority and the dissenting juror.
+fn tally_with_dissent(
+    env: &Env,
+    client: &EscrowContractClient<'_>,
+    dispute_id: u64,
+    jurors: &Vec<Address>,
+    vote: DisputeResolution,
+) -> (Vec<Address>, Address) {
+    let dissent = if vote == DisputeResolution::RelFunds {
+        DisputeResolution::RefBackers
+    } else {
+        DisputeResolution::RelFunds
+    };
+    let salt = test_salt(env);
+    let minority = jurors.get(jurors.len() - 1).unwrap();
+    let majority = jurors.slice(0..jurors.len() - 1);
+    commit_all(client, dispute_id, &majority, vote, &salt);
+    let minority_commitment = client.compute_commitment(&dissent, &0, &salt);
+    client.commit_vote(&dispute_id, &minority, &minority, &minority_commitment);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(client, dispute_id, &majority, vote, &salt);
+    client.reveal_vote(&dispute_id, &minority, &minority, &dissent, &0, &salt);
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+    (majority, minority)
+}
+
+#[test]
+fn test_tally_reports_juror_scores_to_reputation() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 7);
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    client.configure_reputation_contract(&admin, &reputation_id);
+
+    client.select_jury(&dispute_id);
+    let jurors = client.get_juror_assignments(&dispute_id);
+    for juror in jurors.iter() {
+        reputation.set_score(&juror, &shared::REPUTATION_START);
+    }
+
+    // The last juror votes against the majority and is slashed
+    let (majority, minority) = tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+    );
+
+    for juror in majority.iter() {
+        assert_eq!(
+            reputation.get_profile(&juror).score,
+            shared::REPUTATION_START + shared::JUROR_SERVICE_SCORE
+        );
+    }
+    assert_eq!(
+        reputation.get_profile(&minority).score,
+        shared::REPUTATION_START - shared::JUROR_SLASH_SCORE
+    );
+}
+
+// ── Dispute lifecycle events ──
+
+/// The `(old, new)` status pairs published for a dispute so far
+fn dispute_status_changes(env: &Env, dispute_id: u64) -> Vec<(u32, u32)> {
+    let topics: Vec<Val> = (shared::events::DISPUTE_STATUS_CHANGED,).into_val(env);
+    let mut changes = Vec::new(env);
+    for (_, event_topics, data) in env.events().all().iter() {
+        if event_topics == topics {
+            let (id, old, new) = <(u64, u32, u32)>::from_val(env, &data);
+            if id == dispute_id {
+                changes.push_back((old, new));
+            }
+        }
+    }
+    changes
+}
+
+#[test]
+fn test_dispute_status_changes_through_lifecycle() {
+    let env = Env::default();
+    let (client, _, _, dispute_id) = setup_pending_dispute(&env, 20);
+    client.select_jury(&dispute_id);
+    let jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+
+    client.file_appeal(&dispute_id, &Address::generate(&env));
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &appeal_jurors);
+
+    env.ledger().set_timestamp(
+        client.get_dispute(&dispute_id).created_at
+            + core::cmp::max(shared::RESOLUTION_TIME_LOCK, shared::APPEAL_WINDOW_PERIOD)
+            + 1,
+    );
+    client.execute_resolution(&dispute_id);
+
+    // Pending=0, Voting=2, Resolved=3, Appealed=4, FinalResolved=5
+    assert_eq!(
+        dispute_status_changes(&env, dispute_id),
+        Vec::from_array(
+            &env,
+            [(0, 0), (0, 2), (2, 3), (3, 4), (4, 2), (2, 3), (3, 5)]
+        )
+    );
+}
+
+// ── Slash compensation ──
+
+/// Move past the appeal window and time-lock of a resolved dispute, then enforce it
+fn execute_after_appeal_window(env: &Env, client: &EscrowContractClient<'_>, dispute_id: u64) {
+    env.ledger().set_timestamp(
+        client.get_dispute(&dispute_id).created_at
+            + core::cmp::max(shared::RESOLUTION_TIME_LOCK, shared::APPEAL_WINDOW_PERIOD)
+            + 1,
+    );
+    client.execute_resolution(&dispute_id);
+}
+
+#[test]
+fn test_release_pays_creator_share_of_slash() {
+    let env = Env::default();
+    let (client, creator, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    // Switch stakes to a ledger token so payouts can be observed
+    let token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+    client.configure_dispute_token(&token);
+
+    tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+    );
+
+    // Held on the dispute until the outcome is final
+    let slashed = shared::MIN_JUROR_STAKE / 10;
+    let compensation = slashed * shared::SLASH_TO_WINNER_BPS as i128 / 10_000;
+    assert_eq!(ledger.balance(&creator), 0);
+    assert_eq!(
+        client.get_dispute(&dispute_id).pending_compensation,
+        compensation
+    );
+
+    execute_after_appeal_window(&env, &client, dispute_id);
+    assert_eq!(ledger.balance(&creator), compensation);
+    assert_eq!(client.get_available_balance(&1, &token), 0);
+    assert_eq!(client.get_dispute(&dispute_id).pending_compensation, 0);
+}
+
+#[test]
+fn test_refund_credits_escrow_share_of_slash() {
+    let env = Env::default();
+    let (client, creator, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+    client.configure_dispute_token(&token);
+
+    tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RefBackers,
+    );
+
+    // The share stays in the contract, credited to the escrow for backers once final
+    let slashed = shared::MIN_JUROR_STAKE / 10;
+    let compensation = slashed * shared::SLASH_TO_WINNER_BPS as i128 / 10_000;
+    assert_eq!(client.get_available_balance(&1, &token), 0);
+    execute_after_appeal_window(&env, &client, dispute_id);
+    assert_eq!(ledger.balance(&creator), 0);
+    assert_eq!(client.get_available_balance(&1, &token), compensation);
+    let stats = client.get_dispute_stats();
+    let mut rewards = 0;
+    for juror in jurors.iter() {
+        rewards += juror_info(&env, &client, &juror).rewards_accrued;
+    }
+    assert_eq!(
+        stats.total_staked + stats.fee_pool + rewards,
+        7 * 500_0000000 - compensation
+    );
+}
+
+#[test]
+fn test_tie_goes_to_refunding_backers() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let salt = test_salt(&env);
+
+    // Release votes come first in panel order, but the tie is still broken by outcome
+    let release = jurors.slice(0..3);
+    let refund = jurors.slice(3..6);
+    commit_all(
+        &client,
+        dispute_id,
+        &release,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    commit_all(
+        &client,
+        dispute_id,
+        &refund,
+        DisputeResolution::RefBackers,
+        &salt,
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        &client,
+        dispute_id,
+        &release,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    reveal_all(
+        &client,
+        dispute_id,
+        &refund,
+        DisputeResolution::RefBackers,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.resolution, DisputeResolution::RefBackers);
+}
+
+/// Commit and reveal each group's vote, then tally once the reveal grace has passed
+fn tally_groups(
+    env: &Env,
+    client: &EscrowContractClient<'_>,
+    dispute_id: u64,
+    groups: &[(Vec<Address>, DisputeResolution)],
+) {
+    let salt = test_salt(env);
+    for (jurors, vote) in groups {
+        commit_all(client, dispute_id, jurors, *vote, &salt);
+    }
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    for (jurors, vote) in groups {
+        reveal_all(client, dispute_id, jurors, *vote, &salt);
+    }
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+}
+
+#[test]
+fn test_abstaining_juror_is_not_slashed_and_cannot_win() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+
+    // Abstentions outnumber either side but leave the decision to the others
+    let abstain = jurors.slice(0..4);
+    let release = jurors.slice(4..6);
+    let refund = jurors.get(6).unwrap();
+    tally_groups(
+        &env,
+        &client,
+        dispute_id,
+        &[
+            (abstain.clone(), DisputeResolution::Abstain),
+            (release.clone(), DisputeResolution::RelFunds),
+            (
+                Vec::from_array(&env, [refund.clone()]),
+                DisputeResolution::RefBackers,
+            ),
+        ],
+    );
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).resolution,
+        DisputeResolution::RelFunds
+    );
+    for juror in abstain.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.staked_amount, shared::MIN_JUROR_STAKE);
+        assert_eq!(info.total_slashed, 0);
+        assert_eq!(info.missed_votes, 0);
+        assert_eq!(info.successful_votes, 0);
+        assert_eq!(info.rewards_accrued, 0);
+        assert_eq!(info.active_disputes, 0);
+    }
+    assert!(juror_info(&env, &client, &refund).total_slashed > 0);
+    for juror in release.iter() {
+        assert_eq!(juror_info(&env, &client, &juror).successful_votes, 1);
+    }
+}
+
+#[test]
+fn test_panel_that_only_abstains_is_replaced() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    tally_groups(
+        &env,
+        &client,
+        dispute_id,
+        &[(jurors.clone(), DisputeResolution::Abstain)],
+    );
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.resolution, DisputeResolution::NoRes);
+    assert_ne!(dispute.status, shared::types::DisputeStatus::Resolved);
+    for juror in jurors.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.total_slashed, 0);
+        assert_eq!(info.missed_votes, 0);
+    }
+}
+
+#[test]
+fn test_fee_pool_grows_after_slash() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    assert_eq!(client.get_fee_pool(), 0);
+
+    let juror = jurors.get(0).unwrap();
+    env.as_contract(&client.address, || {
+        EscrowContract::slash_juror(&env, juror.clone(), 1000, 0).unwrap();
+    });
+    let slashed = shared::MIN_JUROR_STAKE / 10;
+    assert_eq!(client.get_fee_pool(), slashed);
+
+    // Paying the majority drains the pool down to rounding leftovers
+    let (majority, _) = tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+    );
+    let mut paid = 0;
+    for juror in majority.iter() {
+        paid += juror_info(&env, &client, &juror).rewards_accrued;
+    }
+    let pooled = slashed + slashed - slashed * shared::SLASH_TO_WINNER_BPS as i128 / 10_000;
+    assert_eq!(client.get_fee_pool(), pooled - paid);
+    assert_eq!(client.get_fee_pool(), client.get_dispute_stats().fee_pool);
+}
+
+#[test]
+fn test_total_slashed_accumulates_across_slashes() {
+    let env = Env::default();
+    let (client, _, _, jurors) = setup_voting_dispute(&env, 7);
+    let juror = jurors.get(0).unwrap();
+    assert_eq!(client.get_juror(&juror).total_slashed, 0);
+
+    let mut slashed = 0;
+    for _ in 0..2 {
+        slashed += env.as_contract(&client.address, || {
+            EscrowContract::slash_juror(&env, juror.clone(), 1000, 0).unwrap()
+        });
+    }
+
+    let info = client.get_juror(&juror);
+    assert_eq!(
+        slashed,
+        shared::MIN_JUROR_STAKE / 10 + shared::MIN_JUROR_STAKE * 9 / 100
+    );
+    assert_eq!(info.total_slashed, slashed);
+    assert_eq!(info.staked_amount, shared::MIN_JUROR_STAKE - slashed);
+}
+
+#[test]
+fn test_withdrawn_rewards_are_out_of_reach_of_slashing() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+    client.configure_dispute_token(&token);
+
+    let (majority, _) = tally_with_dissent(
+        &env,
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+    );
+    let stake = shared::MIN_JUROR_STAKE;
+    let slashed = stake / 10;
+    let reward =
+        (slashed - slashed * shared::SLASH_TO_WINNER_BPS as i128 / 10_000) / majority.len() as i128;
+
+    // Rewards accrue apart from the stake and can be withdrawn while registered
+    let juror = majority.get(0).unwrap();
+    let info = juror_info(&env, &client, &juror);
+    assert_eq!(info.staked_amount, stake);
+    assert_eq!(info.rewards_accrued, reward);
+    assert_eq!(client.withdraw_rewards(&juror), reward);
+    assert_eq!(ledger.balance(&juror), reward);
+    assert_eq!(juror_info(&env, &client, &juror).rewards_accrued, 0);
+    assert_eq!(client.try_withdraw_rewards(&juror), Err(Ok(Error::NoClaim)));
+
+    // A later slash only draws on the stake
+    env.as_contract(&client.address, || {
+        EscrowContract::slash_juror(&env, juror.clone(), 1000, 0).unwrap();
+    });
+    let info = juror_info(&env, &client, &juror);
+    assert_eq!(info.staked_amount, stake - stake / 10);
+    assert_eq!(info.rewards_accrued, 0);
+    assert_eq!(ledger.balance(&juror), reward);
+
+    // Deregistering pays out the stake together with unwithdrawn rewards
+    let other = majority.get(1).unwrap();
+    client.deregister_as_juror(&other);
+    assert_eq!(ledger.balance(&other), stake + reward);
+}
+
+#[test]
+fn test_reduce_stake_keeps_juror_registered() {
+    let env = Env::default();
+    let (client, _, _, dispute_id) = setup_pending_dispute(&env, 7);
+    let stake = shared::MIN_JUROR_STAKE;
+
+    let juror = Address::generate(&env);
+    client.register_as_juror(&juror, &(3 * stake));
+    client.reduce_stake(&juror, &(2 * stake));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (shared::events::STAKE_REDUCED,).into_val(&env));
+    assert_eq!(
+        <(Address, i128, i128)>::from_val(&env, &data),
+        (juror.clone(), 2 * stake, stake)
+    );
+    assert_eq!(juror_info(&env, &client, &juror).staked_amount, stake);
+    assert_eq!(client.get_dispute_stats().active_jurors, 8);
+
+    // The remaining stake may not drop below the minimum
+    assert_eq!(
+        client.try_reduce_stake(&juror, &1),
+        Err(Ok(Error::JurorStakeL))
+    );
+    assert_eq!(
+        client.try_reduce_stake(&juror, &0),
+        Err(Ok(Error::InvInput))
+    );
+
+    // Nor can a juror seated on a panel reduce their exposure
+    client.select_jury(&dispute_id);
+    let seated = client.get_juror_assignments(&dispute_id).get(0).unwrap();
+    env.as_contract(&client.address, || {
+        let mut info = crate::storage::get_juror(&env, &seated).unwrap();
+        info.staked_amount = 2 * stake;
+        crate::storage::set_juror(&env, &seated, &info);
+    });
+    assert_eq!(
+        client.try_reduce_stake(&seated, &stake),
+        Err(Ok(Error::JurorAct))
+    );
+}
+
+// ── Per-project dispute token ──
+
+fn tally_unanimous(
+    env: &Env,
+    client: &EscrowContractClient<'_>,
+    dispute_id: u64,
+    jurors: &Vec<Address>,
+) {
+    let salt = test_salt(env);
+    commit_all(
+        client,
+        dispute_id,
+        jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        client,
+        dispute_id,
+        jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+}
+
+#[test]
+fn test_appeal_fee_charged_in_project_dispute_token() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 20);
+    let global_token = env.register_contract(None, MockLedgerToken);
+    let project_token = env.register_contract(None, MockLedgerToken);
+    client.configure_dispute_token(&global_token);
+    client.configure_project_dispute_token(&1, &project_token);
+    assert_eq!(client.get_project_dispute_token(&1), project_token);
+
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    let appellant = Address::generate(&env);
+    client.file_appeal(&dispute_id, &appellant);
+
+    let project_ledger = MockLedgerTokenClient::new(&env, &project_token);
+    let global_ledger = MockLedgerTokenClient::new(&env, &global_token);
+    assert_eq!(project_ledger.balance(&appellant), -shared::APPEAL_FEE);
+    assert_eq!(project_ledger.balance(&client.address), shared::APPEAL_FEE);
+    assert_eq!(global_ledger.balance(&appellant), 0);
+
+    // The appeal panel is paid from the project pool in the project token
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &appeal_jurors);
+    let reward = shared::APPEAL_FEE / appeal_jurors.len() as i128;
+    for juror in appeal_jurors.iter() {
+        assert_eq!(project_ledger.balance(&juror), reward);
+    }
+}
+
+#[test]
+fn test_appeal_fee_falls_back_to_global_token() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 20);
+    let global_token = env.register_contract(None, MockLedgerToken);
+    let project_token = env.register_contract(None, MockLedgerToken);
+    client.configure_dispute_token(&global_token);
+    client.configure_project_dispute_token(&1, &project_token);
+    client.clear_project_dispute_token(&1);
+    assert_eq!(client.get_project_dispute_token(&1), global_token);
+
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    let appellant = Address::generate(&env);
+    client.file_appeal(&dispute_id, &appellant);
+
+    let global_ledger = MockLedgerTokenClient::new(&env, &global_token);
+    let project_ledger = MockLedgerTokenClient::new(&env, &project_token);
+    assert_eq!(global_ledger.balance(&appellant), -shared::APPEAL_FEE);
+    assert_eq!(project_ledger.balance(&appellant), 0);
+}
+
+#[test]
+fn test_appeal_fee_scales_with_appeal_count() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 40);
+    let token = env.register_contract(None, MockLedgerToken);
+    client.configure_dispute_token(&token);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    let first = Address::generate(&env);
+    let pool_before = client.get_dispute_stats().fee_pool;
+    client.file_appeal(&dispute_id, &first);
+    let first_fee = -ledger.balance(&first);
+    assert_eq!(first_fee, shared::APPEAL_FEE);
+    assert_eq!(client.get_dispute_stats().fee_pool, pool_before + first_fee);
+
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &appeal_jurors);
+    let second = Address::generate(&env);
+    let pool_before = client.get_dispute_stats().fee_pool;
+    client.file_appeal(&dispute_id, &second);
+    let second_fee = -ledger.balance(&second);
+    assert_eq!(second_fee, 2 * shared::APPEAL_FEE);
+    assert!(second_fee > first_fee);
+    assert_eq!(
+        client.get_dispute_stats().fee_pool,
+        pool_before + second_fee
+    );
+    assert_eq!(ledger.balance(&client.address), first_fee + second_fee);
+}
+
+#[test]
+fn test_dispute_records_each_appellant() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 40);
+    assert_eq!(client.get_dispute_appellants(&dispute_id).len(), 0);
+
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    let first = Address::generate(&env);
+    client.file_appeal(&dispute_id, &first);
+
+    let appeal_jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &appeal_jurors);
+    let second = Address::generate(&env);
+    client.file_appeal(&dispute_id, &second);
+
+    assert_eq!(
+        client.get_dispute_appellants(&dispute_id),
+        Vec::from_array(&env, [first, second])
+    );
+    assert_eq!(client.get_dispute(&dispute_id).appeal_count, 2);
+}
+
+#[test]
+fn test_select_jury_reports_insufficient_jurors() {
+    let env = Env::default();
+    let (client, _, _, dispute_id) = setup_pending_dispute(&env, shared::JURY_SIZE - 1);
+    assert_eq!(
+        client.try_select_jury(&dispute_id),
+        Err(Ok(Error::InsufficientJurors))
+    );
+
+    // A dispute that already has a panel is a state error instead
+    client.register_as_juror(&Address::generate(&env), &500_0000000);
+    client.select_jury(&dispute_id);
+    assert_eq!(
+        client.try_select_jury(&dispute_id),
+        Err(Ok(Error::InvInput))
+    );
+
+    // Ten active jurors cannot seat an appeal panel
+    let jurors = client.get_juror_assignments(&dispute_id);
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    client.register_as_juror(&Address::generate(&env), &500_0000000);
+    client.register_as_juror(&Address::generate(&env), &500_0000000);
+    client.register_as_juror(&Address::generate(&env), &500_0000000);
+    assert_eq!(
+        client.try_file_appeal(&dispute_id, &Address::generate(&env)),
+        Err(Ok(Error::InsufficientJurors))
+    );
+}
+
+// ── Reentrancy guard ──
+
+#[test]
+fn test_reentrant_token_callback_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize_admin(&Address::generate(&env));
+
+    let token_id = env.register_contract(None, MockReentrantToken);
+    let token = MockReentrantTokenClient::new(&env, &token_id);
+    token.set_target(&client.address);
+    client.configure_dispute_token(&token_id);
+
+    let juror = Address::generate(&env);
+    client.register_as_juror(&juror, &500_0000000);
+
+    assert!(token.reentry_rejected());
+    assert_eq!(
+        client.try_register_as_juror(&juror, &500_0000000),
+        Err(Ok(Error::JurorReg))
+    );
+}
+
+#[test]
+fn test_guarded_entrypoint_fails_while_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize_admin(&Address::generate(&env));
+    client.configure_dispute_token(&create_mock_token(&env));
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_lock(&env).unwrap();
+    });
+    let result = client.try_register_as_juror(&Address::generate(&env), &500_0000000);
+    assert_eq!(result, Err(Ok(Error::Reentrancy)));
+}
+
+// ── Emergency withdrawal ──
+
+#[test]
+fn test_emergency_withdraw_blocked_while_unpaused() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+    let (admin, _, token, _, client) = setup_with_admin(&env);
+    client.deposit(&1, &1000);
+
+    let result = client.try_emergency_withdraw(&admin, &1, &Address::generate(&env), &500);
+    assert_eq!(result, Err(Ok(Error::InvStatus)));
+    assert_eq!(client.get_available_balance(&1, &token), 1000);
+}
+
+#[test]
+fn test_emergency_withdraw_capped_at_available_balance() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.pause(&admin);
+
+    let rescue = Address::generate(&env);
+    assert_eq!(client.emergency_withdraw(&admin, &1, &rescue, &400), 400);
+    assert_eq!(client.get_available_balance(&1, &token), 600);
+
+    // Asking for more than remains only drains what is left
+    assert_eq!(client.emergency_withdraw(&admin, &1, &rescue, &5000), 600);
+    assert_eq!(client.get_available_balance(&1, &token), 0);
+    assert_eq!(client.get_escrow(&1).released_amount, 1000);
+
+    let result = client.try_emergency_withdraw(&admin, &1, &rescue, &1);
+    assert_eq!(result, Err(Ok(Error::EscrowInsuf)));
+}
+
+// ── Reveal grace ──
+
+fn juror_info(
+    env: &Env,
+    client: &EscrowContractClient,
+    juror: &Address,
+) -> shared::types::JurorInfo {
+    env.as_contract(&client.address, || {
+        crate::storage::get_juror(env, juror).unwrap()
+    })
+}
+
+#[test]
+fn test_grace_reveal_counted_but_not_rewarded() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 20);
+    let salt = test_salt(&env);
+    let stake = 500_0000000;
+
+    // Juror 0 never takes part; the last juror reveals during the grace period
+    let absent = jurors.get(0).unwrap();
+    let late = jurors.get(jurors.len() - 1).unwrap();
+    let mut on_time = jurors.clone();
+    on_time.remove(jurors.len() - 1);
+    on_time.remove(0);
+
+    let mut committed = on_time.clone();
+    committed.push_back(late.clone());
+    commit_all(
+        &client,
+        dispute_id,
+        &committed,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    let reveal_start = env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1;
+    env.ledger().set_timestamp(reveal_start);
+    reveal_all(
+        &client,
+        dispute_id,
+        &on_time,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+
+    let reveal_end = reveal_start - 1 + shared::VOTING_REVEAL_PERIOD;
+    env.ledger().set_timestamp(reveal_end + 1);
+    client.reveal_vote(
+        &dispute_id,
+        &late,
+        &late,
+        &DisputeResolution::RelFunds,
+        &0,
+        &salt,
+    );
+
+    // Tallying waits for the grace period to run out
+    assert_eq!(client.try_tally_votes(&dispute_id), Err(Ok(Error::VoteNA)));
+    env.ledger()
+        .set_timestamp(reveal_end + shared::REVEAL_GRACE_PERIOD + 1);
+    client.tally_votes(&dispute_id);
+
+    // The absent juror's slash, less the winner's share, goes only to the on-time majority
+    let slash = shared::MIN_JUROR_STAKE / 10;
+    let compensation = slash * shared::SLASH_TO_WINNER_BPS as i128 / 10_000;
+    let reward = (slash - compensation) / on_time.len() as i128;
+    for juror in on_time.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.staked_amount, stake);
+        assert_eq!(info.rewards_accrued, reward);
+    }
+    let late_info = juror_info(&env, &client, &late);
+    assert_eq!(late_info.staked_amount, stake);
+    assert_eq!(late_info.rewards_accrued, 0);
+    assert_eq!(late_info.successful_votes, 1);
+    assert_eq!(
+        juror_info(&env, &client, &absent).staked_amount,
+        stake - slash
+    );
+}
+
+#[test]
+fn test_reveal_after_grace_rejected() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 20);
+    let salt = test_salt(&env);
+    let juror = jurors.get(0).unwrap();
+
+    commit_all(
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp()
+            + shared::VOTING_COMMIT_PERIOD
+            + shared::VOTING_REVEAL_PERIOD
+            + shared::REVEAL_GRACE_PERIOD
+            + 1,
+    );
+    assert_eq!(
+        client.try_reveal_vote(
+            &dispute_id,
+            &juror,
+            &juror,
+            &DisputeResolution::RelFunds,
+            &0,
+            &salt
+        ),
+        Err(Ok(Error::RevealNA))
+    );
+}
+
+#[test]
+fn test_grace_only_extends_committed_jurors() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let salt = test_salt(&env);
+    let uncommitted = jurors.get(0).unwrap();
+    let committed = jurors.slice(1..jurors.len());
+
+    commit_all(
+        &client,
+        dispute_id,
+        &committed,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + shared::VOTING_REVEAL_PERIOD + 1,
+    );
+
+    // Within the grace period a committed juror still reveals, an uncommitted one cannot
+    let late = committed.get(0).unwrap();
+    client.reveal_vote(
+        &dispute_id,
+        &late,
+        &late,
+        &DisputeResolution::RelFunds,
+        &0,
+        &salt,
+    );
+    assert_eq!(
+        client.try_reveal_vote(
+            &dispute_id,
+            &uncommitted,
+            &uncommitted,
+            &DisputeResolution::RelFunds,
+            &0,
+            &salt
+        ),
+        Err(Ok(Error::NotFound))
+    );
+    let commitment = client.compute_commitment(&DisputeResolution::RelFunds, &0, &salt);
+    assert_eq!(
+        client.try_commit_vote(&dispute_id, &uncommitted, &uncommitted, &commitment),
+        Err(Ok(Error::VoteNA))
+    );
+}
+
+// ── Incremental validator updates ──
+
+#[test]
+fn test_add_and_remove_validator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, validators, client) = setup_with_admin(&env);
+
+    let extra = Address::generate(&env);
+    client.add_validator(&admin, &1, &extra);
+    assert_eq!(client.get_escrow(&1).validators.len(), 4);
+
+    // Adding the same validator twice is rejected
+    assert_eq!(
+        client.try_add_validator(&admin, &1, &extra),
+        Err(Ok(Error::InvInput))
+    );
+
+    let removed = validators.get(0).unwrap();
+    client.remove_validator(&admin, &1, &removed);
+    let remaining = client.get_escrow(&1).validators;
+    assert_eq!(remaining.len(), 3);
+    assert!(!remaining.contains(&removed));
+    assert!(remaining.contains(&extra));
+
+    assert_eq!(
+        client.try_remove_validator(&admin, &1, &removed),
+        Err(Ok(Error::NotValidator))
+    );
+}
+
+#[test]
+fn test_remove_validator_discards_pending_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, validators, client) = setup_with_admin(&env);
+    client.add_validator(&admin, &1, &Address::generate(&env));
+    client.add_validator(&admin, &1, &Address::generate(&env));
+
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[2u8; 32]),
+        &test_uri(&env),
+    );
+    let removed = validators.get(0).unwrap();
+    client.vote_milestone(&1, &0, &removed, &true);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+
+    // The removed validator's approval no longer counts towards the smaller set
+    client.remove_validator(&admin, &1, &removed);
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.approval_count, 0);
+    assert_eq!(milestone.rejection_count, 0);
+
+    client.vote_milestone(&1, &0, &validators.get(2).unwrap(), &false);
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Submitted
+    );
+
+    // Re-adding the validator leaves no stale vote behind
+    client.add_validator(&admin, &1, &removed);
+    client.vote_milestone(&1, &0, &removed, &true);
+    assert_eq!(client.get_milestone(&1, &0).approval_count, 1);
+}
+
+#[test]
+fn test_remove_validator_keeps_minimum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, validators, client) = setup_with_admin(&env);
+
+    let result = client.try_remove_validator(&admin, &1, &validators.get(0).unwrap());
+    assert_eq!(result, Err(Ok(Error::InvInput)));
+    assert_eq!(
+        client.get_escrow(&1).validators.len(),
+        shared::MIN_VALIDATORS
+    );
+}
+
+#[test]
+fn test_operator_manages_only_own_project() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, creator, token, validators, client) = setup_with_admin(&env);
+    client.initialize(
+        &2,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+
+    let operator = Address::generate(&env);
+    let mut new_validators = validators.clone();
+    new_validators.push_back(Address::generate(&env));
+    assert_eq!(
+        client.try_update_validators(&operator, &1, &new_validators),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_operator(&1, &operator);
+    assert_eq!(client.get_operator(&1), Some(operator.clone()));
+    assert_eq!(client.get_operator(&2), None);
+
+    client.update_validators(&operator, &1, &new_validators);
+    assert_eq!(client.get_escrow(&1).validators, new_validators);
+
+    // The operator has no say over another project
+    assert_eq!(
+        client.try_update_validators(&operator, &2, &new_validators),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_validator(&operator, &2, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The platform admin keeps access to every project
+    client.update_validators(&admin, &2, &new_validators);
+    assert_eq!(client.get_escrow(&2).validators, new_validators);
+}
+
+#[test]
+fn test_update_validators_discards_pending_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, validators, client) = setup_with_admin(&env);
+
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[2u8; 32]),
+        &test_uri(&env),
+    );
+    let kept = validators.get(0).unwrap();
+    client.vote_milestone(&1, &0, &kept, &true);
+
+    // Same behaviour as batch_update_validators: the new set votes from scratch
+    let mut roster = Vec::from_array(&env, [kept.clone()]);
+    roster.push_back(Address::generate(&env));
+    roster.push_back(Address::generate(&env));
+    client.update_validators(&admin, &1, &roster);
+    assert_eq!(client.get_milestone(&1, &0).approval_count, 0);
+
+    client.vote_milestone(&1, &0, &kept, &true);
+    assert_eq!(client.get_milestone(&1, &0).approval_count, 1);
+}
+
+#[test]
+fn test_batch_update_validators_skips_unknown_project() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.initialize(
+        &2,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+
+    // A vote cast by the outgoing set on project 1
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[2u8; 32]),
+        &test_uri(&env),
+    );
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+
+    let mut roster = Vec::new(&env);
+    for _ in 0..3 {
+        roster.push_back(Address::generate(&env));
+    }
+    let mut updates = Vec::new(&env);
+    updates.push_back((1u64, roster.clone()));
+    updates.push_back((2u64, roster.clone()));
+    updates.push_back((99u64, roster.clone()));
+
+    let result = client.batch_update_validators(&admin, &updates);
+    assert_eq!(result.succeeded, 2);
+    assert_eq!(result.failed, Vec::from_array(&env, [99u64]));
+    assert_eq!(client.get_escrow(&1).validators, roster);
+    assert_eq!(client.get_escrow(&2).validators, roster);
+    assert_eq!(client.get_milestone(&1, &0).approval_count, 0);
+
+    // A set below MIN_VALIDATORS is reported as failed and left unapplied
+    let mut short = Vec::new(&env);
+    short.push_back((2u64, Vec::from_array(&env, [creator.clone()])));
+    let result = client.batch_update_validators(&admin, &short);
+    assert_eq!(result.succeeded, 0);
+    assert_eq!(result.failed, Vec::from_array(&env, [2u64]));
+    assert_eq!(client.get_escrow(&2).validators, roster);
+}
+
+#[test]
+fn test_get_milestone_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, _, _, client) = setup_with_admin(&env);
+
+    assert_eq!(client.get_milestone_count(&1), 0);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &300);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[2u8; 32]), &300);
+    assert_eq!(client.get_milestone_count(&1), 2);
+
+    assert_eq!(
+        client.try_get_milestone_count(&99),
+        Err(Ok(Error::NotFound))
+    );
+}
+
+// ── Partial milestone approval ──
+
+#[test]
+fn test_partial_votes_release_median_percentage() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(&1, &creator, &token, &validators, &10000, &0, &0, &0, &0);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    let percentages = [8000u32, 10000, 9000];
+    for (i, bps) in percentages.iter().enumerate() {
+        client.vote_milestone_partial(&1, &0, &validators.get(i as u32).unwrap(), bps);
+    }
+
+    // Median of 80/90/100% of 500
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.status, MilestoneStatus::Approved);
+    assert_eq!(client.get_escrow(&1).released_amount, 450);
+    assert_eq!(client.get_available_balance(&1, &token), 550);
+}
+
+#[test]
+fn test_partial_vote_mixes_with_full_approvals() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(&1, &creator, &token, &validators, &10000, &0, &0, &0, &0);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    let result = client.try_vote_milestone_partial(&1, &0, &validators.get(0).unwrap(), &10001);
+    assert_eq!(result, Err(Ok(Error::InvInput)));
+
+    client.vote_milestone_partial(&1, &0, &validators.get(0).unwrap(), &5000);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+    client.vote_milestone(&1, &0, &validators.get(2).unwrap(), &true);
+
+    // Full approvals count as 100%: median of 50/100/100%
+    assert_eq!(client.get_escrow(&1).released_amount, 500);
+}
+
+// ── Changing votes ──
+
+#[test]
+fn test_change_vote_flips_approval_to_rejection() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    let first = validators.get(0).unwrap();
+    let second = validators.get(1).unwrap();
+
+    assert_eq!(
+        client.try_change_vote(&1, &0, &first, &false),
+        Err(Ok(Error::NotFound))
+    );
+    assert_eq!(
+        client.vote_milestone(&1, &0, &first, &true),
+        VoteOutcome::Recorded
+    );
+
+    assert_eq!(
+        client.change_vote(&1, &0, &first, &false),
+        VoteOutcome::Recorded
+    );
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.approval_count, 0);
+    assert_eq!(milestone.rejection_count, 1);
+    assert_eq!(
+        client.try_change_vote(&1, &0, &first, &false),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // The flipped vote now counts towards rejection
+    assert_eq!(
+        client.vote_milestone(&1, &0, &second, &false),
+        VoteOutcome::Rejected
+    );
+    assert_eq!(
+        client.try_change_vote(&1, &0, &first, &true),
+        Err(Ok(Error::MstoneInv))
+    );
+}
+
+#[test]
+fn test_submit_milestone_stores_proof_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, _, _, client) = setup_with_admin(&env);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+
+    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+    let proof_uri = String::from_str(
+        &env,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.submit_milestone(&1, &0, &proof_hash, &proof_uri);
+
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.proof_hash, proof_hash);
+    assert_eq!(milestone.proof_uri, proof_uri);
+}
+
+#[test]
+fn test_submit_milestone_rejects_oversized_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, _, _, client) = setup_with_admin(&env);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+
+    let long_uri = String::from_bytes(&env, &[b'a'; shared::MAX_URI_LEN as usize + 1]);
+    let result =
+        client.try_submit_milestone(&1, &0, &BytesN::from_array(&env, &[9u8; 32]), &long_uri);
+    assert_eq!(result, Err(Ok(Error::InvInput)));
+}
+
+// ── Admin transfer ──
+
+#[test]
+fn test_transfer_admin_clears_pending_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, _, client) = setup_with_admin(&env);
+
+    client.schedule_upgrade(&admin, &BytesN::from_array(&env, &[7u8; 32]));
+    assert!(client.get_pending_upgrade().is_some());
+
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&admin, &new_admin);
+    assert!(client.get_pending_upgrade().is_none());
+
+    // Only the new admin can act from now on
+    assert_eq!(client.try_pause(&admin), Err(Ok(Error::Unauthorized)));
+    client.schedule_upgrade(&new_admin, &BytesN::from_array(&env, &[8u8; 32]));
+    assert!(client.get_pending_upgrade().is_some());
+}
+
+// ── Juror vote delegation ──
+
+#[test]
+fn test_delegate_commits_and_reveals_for_juror() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let salt = test_salt(&env);
+    let juror = jurors.get(0).unwrap();
+    let delegate = Address::generate(&env);
+    client.delegate_juror_vote(&juror, &delegate);
+
+    let commitment = client.compute_commitment(&DisputeResolution::RelFunds, &0, &salt);
+    client.commit_vote(&dispute_id, &juror, &delegate, &commitment);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    client.reveal_vote(
+        &dispute_id,
+        &juror,
+        &delegate,
+        &DisputeResolution::RelFunds,
+        &0,
+        &salt,
+    );
+
+    // The vote is recorded for the juror, not the delegate
+    let vote = env.as_contract(&client.address, || {
+        crate::storage::get_dispute_vote(&env, dispute_id, &juror).unwrap()
+    });
+    assert!(vote.revealed);
+    assert_eq!(vote.vote, DisputeResolution::RelFunds);
+}
+
+#[test]
+fn test_unapproved_delegate_rejected() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let salt = test_salt(&env);
+    let juror = jurors.get(0).unwrap();
+    client.delegate_juror_vote(&juror, &Address::generate(&env));
+
+    let commitment = client.compute_commitment(&DisputeResolution::RelFunds, &0, &salt);
+    assert_eq!(
+        client.try_commit_vote(&dispute_id, &juror, &Address::generate(&env), &commitment),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_delegate_juror_vote(&Address::generate(&env), &juror),
+        Err(Ok(Error::NotJuror))
+    );
+}
+
+// ── Stale dispute resolution ──
+
+#[test]
+fn test_force_resolve_stale_dispute_slashes_all_non_revealers() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let stake = 500_0000000;
+    let voting_start = env.ledger().timestamp();
+
+    let reveal_end = voting_start + shared::VOTING_COMMIT_PERIOD + shared::VOTING_REVEAL_PERIOD;
+    env.ledger().set_timestamp(reveal_end);
+    assert_eq!(
+        client.try_force_resolve_stale_dispute(&dispute_id),
+        Err(Ok(Error::VoteNA))
+    );
+
+    env.ledger()
+        .set_timestamp(reveal_end + shared::REVEAL_GRACE_PERIOD + 1);
+    // Without reveals the panel misses quorum; no fresh jurors are left to replace it
+    assert_eq!(
+        client.force_resolve_stale_dispute(&dispute_id),
+        DisputeResolution::NoRes
+    );
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::Pending);
+    assert_eq!(dispute.resolution, DisputeResolution::NoRes);
+    for juror in jurors.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.staked_amount, stake - shared::MIN_JUROR_STAKE / 10);
+        assert_eq!(info.missed_votes, 1);
+        assert_eq!(info.active_disputes, 0);
+    }
+}
+
+// ── Dispute parameters ──
+
+fn small_dispute_config() -> shared::types::DisputeConfig {
+    shared::types::DisputeConfig {
+        min_juror_stake: 100_0000000,
+        jury_size: 3,
+        appeal_jury_size: 5,
+        slash_bps: 2000,
+        slash_to_winner_bps: shared::SLASH_TO_WINNER_BPS,
+        appeal_fee: 10_0000000,
+        juror_bond: 0,
+    }
+}
+
+#[test]
+fn test_smaller_configured_jury_size() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 5);
+
+    // Five jurors cannot fill the default panel of seven
+    assert_eq!(
+        client.try_select_jury(&dispute_id),
+        Err(Ok(Error::InsufficientJurors))
+    );
+
+    client.configure_dispute_params(&admin, &small_dispute_config());
+    client.select_jury(&dispute_id);
+    let panel = client.get_juror_assignments(&dispute_id);
+    assert_eq!(panel.len(), 3);
+
+    // Non-revealers are slashed by the configured share of their stake
+    env.ledger().set_timestamp(
+        env.ledger().timestamp()
+            + shared::VOTING_COMMIT_PERIOD
+            + shared::VOTING_REVEAL_PERIOD
+            + shared::REVEAL_GRACE_PERIOD
+            + 1,
+    );
+    client.tally_votes(&dispute_id);
+    for juror in panel.iter() {
+        assert_eq!(
+            juror_info(&env, &client, &juror).staked_amount,
+            500_0000000 - 100_0000000
+        );
+    }
+}
+
+#[test]
+fn test_configured_slash_scales_with_juror_stake() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 3);
+    let mut config = small_dispute_config();
+    config.slash_bps = 2500;
+    client.configure_dispute_params(&admin, &config);
+    client.select_jury(&dispute_id);
+    let panel = client.get_juror_assignments(&dispute_id);
+
+    // The silent juror has topped up to a larger stake than the others
+    let silent = panel.get(0).unwrap();
+    env.as_contract(&client.address, || {
+        let mut info = crate::storage::get_juror(&env, &silent).unwrap();
+        info.staked_amount = 800_0000000;
+        crate::storage::set_juror(&env, &silent, &info);
+    });
+    let mut voting = panel.clone();
+    voting.remove(0);
+    tally_unanimous(&env, &client, dispute_id, &voting);
+
+    // 25% of its own stake is taken, not of the minimum stake
+    assert_eq!(
+        juror_info(&env, &client, &silent).staked_amount,
+        800_0000000 - 200_0000000
+    );
+}
+
+#[test]
+fn test_dispute_params_validated() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup_pending_dispute(&env, 0);
+    assert_eq!(client.get_dispute_params().jury_size, shared::JURY_SIZE);
+
+    let mut even_jury = small_dispute_config();
+    even_jury.jury_size = 4;
+    let mut small_appeal = small_dispute_config();
+    small_appeal.appeal_jury_size = 3;
+    let mut over_slash = small_dispute_config();
+    over_slash.slash_bps = 10001;
+    for config in [even_jury, small_appeal, over_slash] {
+        assert_eq!(
+            client.try_configure_dispute_params(&admin, &config),
+            Err(Ok(Error::InvInput))
+        );
+    }
+    assert_eq!(
+        client.try_configure_dispute_params(&Address::generate(&env), &small_dispute_config()),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+// ── Per-dispute juror bond ──
+
+#[test]
+fn test_juror_bond_returned_to_majority_and_forfeited_by_non_revealer() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 5);
+    let bond = 50_0000000;
+    let stake = 500_0000000;
+    let mut config = small_dispute_config();
+    config.juror_bond = bond;
+    client.configure_dispute_params(&admin, &config);
+    client.select_jury(&dispute_id);
+    let jurors = client.get_juror_assignments(&dispute_id);
+    let silent = jurors.get(0).unwrap();
+    let mut majority = jurors.clone();
+    majority.remove(0);
+
+    // Every juror commits and locks a bond; only the majority reveals
+    let salt = test_salt(&env);
+    commit_all(
+        &client,
+        dispute_id,
+        &jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    assert_eq!(
+        juror_info(&env, &client, &silent).staked_amount,
+        stake - bond
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        &client,
+        dispute_id,
+        &majority,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+
+    // The silent juror loses the bond on top of the slash; the majority shares both,
+    // less the winner's share of the slash
+    let slash = (stake - bond) * config.slash_bps as i128 / 10000;
+    assert_eq!(
+        juror_info(&env, &client, &silent).staked_amount,
+        stake - bond - slash
+    );
+    let compensation = slash * config.slash_to_winner_bps as i128 / 10_000;
+    let reward = (bond + slash - compensation) / majority.len() as i128;
+    for juror in majority.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.staked_amount, stake);
+        assert_eq!(info.rewards_accrued, reward);
+    }
+}
+
+// ── Juror statistics ──
+
+#[test]
+fn test_juror_win_rate_after_resolved_dispute() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    assert_eq!(client.list_active_jurors().len(), 7);
+    let silent = jurors.get(0).unwrap();
+    let voter = jurors.get(1).unwrap();
+    assert_eq!(client.get_juror_win_rate(&voter), 0);
+
+    let mut voting = jurors.clone();
+    voting.remove(0);
+    tally_unanimous(&env, &client, dispute_id, &voting);
+
+    let info = client.get_juror(&voter);
+    assert_eq!((info.successful_votes, info.missed_votes), (1, 0));
+    assert_eq!(client.get_juror_win_rate(&voter), 10000);
+    assert_eq!(client.get_juror_win_rate(&silent), 0);
+
+    // Two wins and one miss
+    env.as_contract(&client.address, || {
+        let mut info = crate::storage::get_juror(&env, &silent).unwrap();
+        info.successful_votes = 2;
+        crate::storage::set_juror(&env, &silent, &info);
+    });
+    assert_eq!(client.get_juror_win_rate(&silent), 6666);
+    assert_eq!(client.get_juror_win_rate(&Address::generate(&env)), 0);
+}
+
+// ── Final resolution cleanup ──
+
+#[test]
+fn test_final_resolution_clears_vote_records() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+
+    env.ledger().set_timestamp(
+        env.ledger().timestamp()
+            + core::cmp::max(shared::RESOLUTION_TIME_LOCK, shared::APPEAL_WINDOW_PERIOD)
+            + 1,
+    );
+    client.execute_resolution(&dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::FinalResolved);
+    assert_eq!(dispute.resolution, DisputeResolution::RelFunds);
+    assert_eq!(
+        client.try_get_juror_assignments(&dispute_id),
+        Err(Ok(Error::NotFound))
+    );
+    env.as_contract(&client.address, || {
+        for juror in jurors.iter() {
+            assert!(crate::storage::get_dispute_vote(&env, dispute_id, &juror).is_err());
+            assert_eq!(
+                crate::storage::get_juror(&env, &juror)
+                    .unwrap()
+                    .active_disputes,
+                0
+            );
+        }
+        assert!(crate::storage::get_past_jurors(&env, dispute_id).is_empty());
+    });
+}
+
+#[test]
+fn test_cleanup_dispute_only_after_final_resolution() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    tally_unanimous(&env, &client, dispute_id, &jurors);
+    assert_eq!(
+        client.try_cleanup_dispute(&dispute_id),
+        Err(Ok(Error::InvInput))
+    );
+
+    // A dispute finalized without the built-in cleanup keeps its vote records
+    env.as_contract(&client.address, || {
+        let mut dispute = crate::storage::get_dispute(&env, dispute_id).unwrap();
+        dispute.status = shared::types::DisputeStatus::FinalResolved;
+        crate::storage::set_dispute(&env, dispute_id, &dispute);
+    });
+    client.cleanup_dispute(&dispute_id);
+
+    assert_eq!(
+        client.try_get_juror_assignments(&dispute_id),
+        Err(Ok(Error::NotFound))
+    );
+    env.as_contract(&client.address, || {
+        for juror in jurors.iter() {
+            assert!(crate::storage::get_dispute_vote(&env, dispute_id, &juror).is_err());
+        }
+        assert!(crate::storage::get_past_jurors(&env, dispute_id).is_empty());
+    });
+
+    // Nothing left to remove is not an error
+    client.cleanup_dispute(&dispute_id);
+}
+
+// ── Milestone release history ──
+
+#[test]
+fn test_full_release_capped_after_partial_dispute_release() {
+    let env = Env::default();
+    let (client, _, creator, partial_id) = setup_pending_dispute(&env, 20);
+    let full_id = client.initiate_dispute(&1, &0, &creator);
+    client.select_jury(&partial_id);
+    client.select_jury(&full_id);
+    let salt = test_salt(&env);
+
+    let partial_jurors = client.get_juror_assignments(&partial_id);
+    let full_jurors = client.get_juror_assignments(&full_id);
+    let partial = client.compute_commitment(&DisputeResolution::PartRel, &4000, &salt);
+    for juror in partial_jurors.iter() {
+        client.commit_vote(&partial_id, &juror, &juror, &partial);
+    }
+    commit_all(
+        &client,
+        full_id,
+        &full_jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    for juror in partial_jurors.iter() {
+        client.reveal_vote(
+            &partial_id,
+            &juror,
+            &juror,
+            &DisputeResolution::PartRel,
+            &4000,
+            &salt,
+        );
+    }
+    reveal_all(
+        &client,
+        full_id,
+        &full_jurors,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&partial_id);
+    client.tally_votes(&full_id);
+    env.ledger().set_timestamp(
+        env.ledger().timestamp()
+            + core::cmp::max(shared::RESOLUTION_TIME_LOCK, shared::APPEAL_WINDOW_PERIOD)
+            + 1,
+    );
+
+    // 40% of the 500 milestone is released by the partial ruling
+    client.execute_resolution(&partial_id);
+    assert_eq!(client.get_milestone_released(&1, &0), 200);
+
+    // The full ruling only releases what is left
+    client.execute_resolution(&full_id);
+    assert_eq!(client.get_milestone_released(&1, &0), 500);
+    assert_eq!(client.get_escrow(&1).released_amount, 500);
+}
+
+// ── Backer conflict of interest ──
+
+#[test]
+fn test_select_jury_excludes_significant_backer() {
+    let env = Env::default();
+    let (client, _, _, dispute_id) = setup_pending_dispute(&env, 8);
+    let project = MockProjectLaunchClient::new(&env, &client.get_project_launch().unwrap());
+    let candidates = client.list_active_jurors();
+    let backer = candidates.get(0).unwrap();
+    let small_backer = candidates.get(1).unwrap();
+    project.set_contribution(
+        &1,
+        &backer,
+        &(shared::constants::MAX_JUROR_CONTRIBUTION + 1),
+    );
+    project.set_contribution(
+        &1,
+        &small_backer,
+        &shared::constants::MAX_JUROR_CONTRIBUTION,
+    );
+
+    client.select_jury(&dispute_id);
+
+    // Seven of the eight candidates are needed, so everyone but the backer sits
+    let jurors = client.get_juror_assignments(&dispute_id);
+    assert_eq!(jurors.len(), 7);
+    assert!(!jurors.contains(&backer));
+    assert!(jurors.contains(&small_backer));
+}
+
+#[test]
+fn test_select_jury_fails_when_backers_leave_too_few_jurors() {
+    let env = Env::default();
+    let (client, _, _, dispute_id) = setup_pending_dispute(&env, 7);
+    let project = MockProjectLaunchClient::new(&env, &client.get_project_launch().unwrap());
+    let backer = client.list_active_jurors().get(0).unwrap();
+    project.set_contribution(
+        &1,
+        &backer,
+        &(shared::constants::MAX_JUROR_CONTRIBUTION + 1),
+    );
+
+    assert_eq!(
+        client.try_select_jury(&dispute_id),
+        Err(Ok(Error::ConflictInt))
+    );
+}
+
+#[test]
+fn test_failing_project_launch_excludes_no_jurors() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 7);
+    let broken = env.register_contract(None, MockBrokenProjectLaunch);
+    client.configure_project_launch(&admin, &broken);
+    assert_eq!(
+        client.try_configure_project_launch(&Address::generate(&env), &broken),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.select_jury(&dispute_id);
+    assert_eq!(client.get_juror_assignments(&dispute_id).len(), 7);
+}
+
+// ── Dispute contribution threshold ──
+
+#[test]
+fn test_raised_dispute_threshold_blocks_small_backer() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    let threshold = shared::constants::MIN_CONTRIBUTION * 5;
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &threshold,
+    );
+    assert_eq!(client.get_escrow(&1).min_dispute_contribution, threshold);
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    let admin = Address::generate(&env);
+    client.initialize_admin(&admin);
+    let project_launch = env.register_contract(None, MockProjectLaunch);
+    client.configure_project_launch(&admin, &project_launch);
+    let project = MockProjectLaunchClient::new(&env, &project_launch);
+    let small_backer = Address::generate(&env);
+    let large_backer = Address::generate(&env);
+    project.set_contribution(&1, &small_backer, &(threshold - 1));
+    project.set_contribution(&1, &large_backer, &threshold);
+
+    assert_eq!(
+        client.try_initiate_dispute(&1, &0, &small_backer),
+        Err(Ok(Error::Unauthorized))
+    );
+    let dispute_id = client.initiate_dispute(&1, &0, &large_backer);
+    assert_eq!(client.get_dispute(&dispute_id).initiator, large_backer);
+}
+
+#[test]
+fn test_backer_cannot_dispute_without_project_launch() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    assert_eq!(client.get_project_launch(), None);
+    assert_eq!(
+        client.try_initiate_dispute(&1, &0, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.initiate_dispute(&1, &0, &creator);
+}
+
+#[test]
+fn test_dispute_threshold_defaults_to_min_contribution() {
+    let (env, creator, token, _, validators) = create_test_env();
+    let client = create_client(&env);
+    env.mock_all_auths();
+
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(
+        client.get_escrow(&1).min_dispute_contribution,
+        shared::constants::MIN_CONTRIBUTION
+    );
+
+    assert_eq!(
+        client.try_initialize(
+            &2,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &0,
+            &0,
+            &0,
+            &-1,
+        ),
+        Err(Ok(Error::InvInput))
+    );
+}
+
+// ── Enforcement preview ──
+
+/// Resolve a dispute with a unanimous `vote` and check the preview against enforcement
+fn assert_preview_matches_execution(
+    vote: DisputeResolution,
+    payload: u32,
+    expected: (MilestoneStatus, i128),
+) {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 20);
+    let salt = test_salt(&env);
+
+    let commitment = client.compute_commitment(&vote, &payload, &salt);
+    for juror in jurors.iter() {
+        client.commit_vote(&dispute_id, &juror, &juror, &commitment);
+    }
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    for juror in jurors.iter() {
+        client.reveal_vote(&dispute_id, &juror, &juror, &vote, &payload, &salt);
+    }
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+    env.ledger().set_timestamp(
+        env.ledger().timestamp()
+            + core::cmp::max(shared::RESOLUTION_TIME_LOCK, shared::APPEAL_WINDOW_PERIOD)
+            + 1,
+    );
+
+    let (status, release) = client.preview_enforcement(&dispute_id);
+    assert_eq!((status, release), expected);
+    client.execute_resolution(&dispute_id);
+
+    assert_eq!(client.get_milestone(&1, &0).status, status);
+    assert_eq!(client.get_milestone_released(&1, &0), release);
+    assert_eq!(client.get_escrow(&1).released_amount, release);
+}
+
+#[test]
+fn test_preview_enforcement_release_funds() {
+    assert_preview_matches_execution(
+        DisputeResolution::RelFunds,
+        0,
+        (MilestoneStatus::Approved, 500),
+    );
+}
+
+#[test]
+fn test_preview_enforcement_refund_backers() {
+    assert_preview_matches_execution(
+        DisputeResolution::RefBackers,
+        0,
+        (MilestoneStatus::Rejected, 0),
+    );
+}
+
+#[test]
+fn test_preview_enforcement_partial_release() {
+    assert_preview_matches_execution(
+        DisputeResolution::PartRel,
+        4000,
+        (MilestoneStatus::Approved, 200),
+    );
+}
+
+// ── Surplus withdrawal ──
+
+#[test]
+fn test_withdraw_surplus_after_milestones_settle() {
+    let (env, creator, _, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let token = env.register_contract(None, MockLedgerToken);
+    let ledger = MockLedgerTokenClient::new(&env, &token);
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &300);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[2u8; 32]), &200);
+
+    for milestone_id in 0..2u64 {
+        client.submit_milestone(
+            &1,
+            &milestone_id,
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+    }
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+
+    // The second milestone is still awaiting votes
+    assert_eq!(client.try_withdraw_surplus(&1), Err(Ok(Error::MstoneInv)));
+
+    client.vote_milestone(&1, &1, &validators.get(0).unwrap(), &false);
+    client.vote_milestone(&1, &1, &validators.get(1).unwrap(), &false);
+
+    // 300 was released and the rejected 200 stays reserved for a dispute
+    assert_eq!(client.withdraw_surplus(&1), 500);
+    assert_eq!(ledger.balance(&creator), 800);
+    assert_eq!(client.get_escrow(&1).total_deposited, 500);
+    assert_eq!(client.try_withdraw_surplus(&1), Err(Ok(Error::EscrowInsuf)));
+}
+
+// ── Vote progress ──
+
+#[test]
+fn test_vote_status_tracks_partial_voting() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    let first = validators.get(0).unwrap();
+    let second = validators.get(1).unwrap();
+    client.vote_milestone(&1, &0, &first, &true);
+    assert!(client.has_voted(&1, &0, &first));
+    assert!(!client.has_voted(&1, &0, &second));
+
+    let status = client.get_vote_status(&1, &0);
+    assert_eq!(status.approval_count, 1);
+    assert_eq!(status.rejection_count, 0);
+    assert_eq!(status.required_approvals, 2);
+    assert_eq!(status.total_validators, validators.len());
+}
+
+#[test]
+fn test_quorum_holds_milestone_until_enough_votes() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    assert_eq!(
+        client.try_initialize(
+            &1,
+            &creator,
+            &token,
+            &validators,
+            &DEFAULT_THRESHOLD,
+            &0,
+            &0,
+            &(validators.len() + 1),
+            &0,
+        ),
+        Err(Ok(Error::InvInput))
+    );
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &3,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    // Two approvals meet the threshold but not the quorum of three votes
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+    let outcome = client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+    assert_eq!(outcome, VoteOutcome::Recorded);
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Submitted
+    );
+
+    let outcome = client.vote_milestone(&1, &0, &validators.get(2).unwrap(), &false);
+    assert_eq!(outcome, VoteOutcome::Approved);
+}
+
+#[test]
+fn test_rejection_reason_stored_and_returned() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    let rejecter = validators.get(0).unwrap();
+    let approver = validators.get(1).unwrap();
+    let reason = BytesN::from_array(&env, &[7u8; 32]);
+    client.vote_milestone_with_reason(&1, &0, &rejecter, &false, &reason);
+    client.vote_milestone_with_reason(
+        &1,
+        &0,
+        &approver,
+        &true,
+        &BytesN::from_array(&env, &[8u8; 32]),
+    );
+
+    // Only reasons behind current rejections are listed
+    assert_eq!(
+        client.get_rejection_reasons(&1, &0),
+        Vec::from_array(&env, [(rejecter.clone(), reason)])
+    );
+    client.change_vote(&1, &0, &rejecter, &true);
+    assert_eq!(client.get_rejection_reasons(&1, &0).len(), 0);
+}
+
+#[test]
+fn test_pending_votes_for_skips_voted_milestones() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    for i in 0..3u8 {
+        client.create_milestone(&1, &BytesN::from_array(&env, &[i; 32]), &100);
+    }
+    for milestone_id in 0..2u64 {
+        client.submit_milestone(
+            &1,
+            &milestone_id,
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+    }
+
+    let validator = validators.get(0).unwrap();
+    client.vote_milestone(&1, &0, &validator, &true);
+    assert_eq!(
+        client.pending_votes_for(&1, &validator),
+        Vec::from_array(&env, [1u64])
+    );
+    assert_eq!(
+        client.try_pending_votes_for(&1, &Address::generate(&env)),
+        Err(Ok(Error::NotValidator))
+    );
+}
+
+// ── Vote deadline ──
+
+#[test]
+fn test_milestone_auto_rejects_after_vote_deadline() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+
+    let deadline = client.get_milestone(&1, &0).vote_deadline;
+    assert_eq!(
+        deadline,
+        env.ledger().timestamp() + shared::MILESTONE_VOTE_PERIOD
+    );
+    assert_eq!(
+        client.try_finalize_milestone_vote(&1, &0),
+        Err(Ok(Error::VoteNA))
+    );
+
+    // One approval short of the threshold when the period runs out
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        client.try_vote_milestone(&1, &0, &validators.get(1).unwrap(), &true),
+        Err(Ok(Error::VoteNA))
+    );
+    assert_eq!(
+        client.finalize_milestone_vote(&1, &0),
+        VoteOutcome::Rejected
+    );
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Rejected
+    );
+    assert_eq!(client.get_escrow(&1).released_amount, 0);
+    assert_eq!(
+        client.try_finalize_milestone_vote(&1, &0),
+        Err(Ok(Error::MstoneInv))
+    );
+}
+
+#[test]
+fn test_finalize_milestone_vote_blocked_while_paused() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1000);
+    env.mock_all_auths();
+    let (admin, _, _, validators, client) = setup_with_admin(&env);
+
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+    env.ledger()
+        .set_timestamp(client.get_milestone(&1, &0).vote_deadline + 1);
+
+    client.pause_project(&admin, &1);
+    assert_eq!(
+        client.try_finalize_milestone_vote(&1, &0),
+        Err(Ok(Error::Paused))
+    );
+    client.resume_project(&admin, &1);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_finalize_milestone_vote(&1, &0),
+        Err(Ok(Error::Paused))
+    );
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Submitted
+    );
+}
+
+// ── Escrow summary ──
+
+#[test]
+fn test_escrow_summary_counts_milestone_states() {
+    let (env, creator, _, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let token = env.register_contract(None, MockLedgerToken);
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    for (i, amount) in [100i128, 200, 150, 250].iter().enumerate() {
+        client.create_milestone(&1, &BytesN::from_array(&env, &[i as u8; 32]), amount);
+    }
+    for milestone_id in 0..3u64 {
+        client.submit_milestone(
+            &1,
+            &milestone_id,
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+    }
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &true);
+    client.vote_milestone(&1, &1, &validators.get(0).unwrap(), &false);
+    client.vote_milestone(&1, &1, &validators.get(1).unwrap(), &false);
+
+    let summary = client.get_escrow_summary(&1);
+    assert_eq!(summary.total_deposited, 1000);
+    assert_eq!(summary.released, 100);
+    assert_eq!(summary.available, 900);
+    assert_eq!(summary.pending_count, 1);
+    assert_eq!(summary.submitted_count, 1);
+    assert_eq!(summary.approved_count, 1);
+    assert_eq!(summary.rejected_count, 1);
+    assert_eq!(summary.cancelled_count, 0);
+    assert_eq!(summary.pending_amount, 400);
+}
+
+// ── Multi-currency milestones ──
+
+#[test]
+fn test_milestones_release_in_their_own_token() {
+    let (env, creator, _, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let usdc = env.register_contract(None, MockLedgerToken);
+    let xlm = env.register_contract(None, MockLedgerToken);
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &usdc,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.deposit_token(&1, &xlm, &400);
+
+    // Each token's milestones are bounded by that token's deposits
+    assert_eq!(
+        client.try_create_milestone_in_token(&1, &BytesN::from_array(&env, &[2u8; 32]), &500, &xlm),
+        Err(Ok(Error::EscrowInsuf))
+    );
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &600);
+    client.create_milestone_in_token(&1, &BytesN::from_array(&env, &[2u8; 32]), &400, &xlm);
+    assert_eq!(client.get_milestone(&1, &0).token, usdc);
+    assert_eq!(client.get_milestone(&1, &1).token, xlm);
+
+    for milestone_id in 0..2u64 {
+        client.submit_milestone(
+            &1,
+            &milestone_id,
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+        client.vote_milestone(&1, &milestone_id, &validators.get(0).unwrap(), &true);
+        client.vote_milestone(&1, &milestone_id, &validators.get(1).unwrap(), &true);
+    }
+
+    assert_eq!(
+        MockLedgerTokenClient::new(&env, &usdc).balance(&creator),
+        600
+    );
+    assert_eq!(
+        MockLedgerTokenClient::new(&env, &xlm).balance(&creator),
+        400
+    );
+    assert_eq!(client.get_available_balance(&1, &usdc), 400);
+    assert_eq!(client.get_available_balance(&1, &xlm), 0);
+    assert_eq!(client.get_escrow(&1).released_amount, 600);
+}
+
+// ── Jury system statistics ──
+
+#[test]
+fn test_dispute_stats_track_stake_through_slash() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 7);
+    let stake: i128 = 500_0000000;
+    assert_eq!(
+        client.get_dispute_stats(),
+        shared::types::DisputeStats {
+            active_jurors: 7,
+            total_staked: 7 * stake,
+            fee_pool: 0,
+            next_dispute_id: 2,
+        }
+    );
+
+    // The silent juror is slashed; the winner's share leaves the system and
+    // the rest is split among the majority
+    let silent = jurors.get(0).unwrap();
+    let mut voting = jurors.clone();
+    voting.remove(0);
+    tally_unanimous(&env, &client, dispute_id, &voting);
+
+    let stats = client.get_dispute_stats();
+    assert_eq!(client.get_juror(&silent).staked_amount, stake - stake / 10);
+    let mut summed = 0;
+    let mut rewards = 0;
+    for juror in jurors.iter() {
+        let info = client.get_juror(&juror);
+        summed += info.staked_amount;
+        rewards += info.rewards_accrued;
+    }
+    let compensation = stake / 10 * shared::SLASH_TO_WINNER_BPS as i128 / 10_000;
+    assert_eq!(stats.total_staked, summed);
+    assert_eq!(
+        stats.total_staked + stats.fee_pool + rewards,
+        7 * stake - compensation
+    );
+
+    client.deregister_as_juror(&silent);
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.active_jurors, 6);
+    assert_eq!(stats.total_staked, summed - (stake - stake / 10));
+}
+
+#[test]
+fn test_resubmit_rejected_milestone_resets_votes() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &500);
+    let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+    client.submit_milestone(&1, &0, &proof_hash, &test_uri(&env));
+
+    // Only rejected milestones can be resubmitted
+    assert_eq!(
+        client.try_resubmit_milestone(&1, &0, &proof_hash, &test_uri(&env)),
+        Err(Ok(Error::MstoneInv))
+    );
+
+    for round in 1..=shared::MAX_RESUBMISSIONS {
+        client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false);
+        client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false);
+        assert_eq!(
+            client.get_milestone(&1, &0).status,
+            MilestoneStatus::Rejected
+        );
+
+        let new_proof = BytesN::from_array(&env, &[round as u8; 32]);
+        client.resubmit_milestone(&1, &0, &new_proof, &test_uri(&env));
+
+        let milestone = client.get_milestone(&1, &0);
+        assert_eq!(milestone.status, MilestoneStatus::Submitted);
+        assert_eq!(milestone.proof_hash, new_proof);
+        assert_eq!(milestone.approval_count, 0);
+        assert_eq!(milestone.rejection_count, 0);
+        assert_eq!(milestone.resubmission_count, round);
+    }
+
+    // Prior voters may vote again on the new round
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &false);
+    client.vote_milestone(&1, &0, &validators.get(1).unwrap(), &false);
+    assert_eq!(
+        client.try_resubmit_milestone(&1, &0, &proof_hash, &test_uri(&env)),
+        Err(Ok(Error::MstoneLimit))
+    );
+}
+
+#[test]
+fn test_validator_votes_recorded_per_milestone() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    for i in 0..2u8 {
+        client.create_milestone(&1, &BytesN::from_array(&env, &[i; 32]), &400);
+        client.submit_milestone(
+            &1,
+            &(i as u64),
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+    }
+    let validator = validators.get(0).unwrap();
+    assert_eq!(client.get_validator_votes(&1, &validator), Vec::new(&env));
+
+    client.vote_milestone(&1, &0, &validator, &true);
+    client.vote_milestone(&1, &1, &validator, &false);
+    assert_eq!(
+        client.get_validator_votes(&1, &validator),
+        Vec::from_array(&env, [(0u64, true), (1u64, false)])
+    );
+
+    // A changed vote replaces the earlier decision
+    client.change_vote(&1, &0, &validator, &false);
+    assert_eq!(
+        client.get_validator_votes(&1, &validator),
+        Vec::from_array(&env, [(1u64, false), (0u64, false)])
+    );
+
+    // Resubmitting a rejected milestone clears its entry
+    client.vote_milestone(&1, &1, &validators.get(1).unwrap(), &false);
+    client.resubmit_milestone(
+        &1,
+        &1,
+        &BytesN::from_array(&env, &[8u8; 32]),
+        &test_uri(&env),
+    );
+    assert_eq!(
+        client.get_validator_votes(&1, &validator),
+        Vec::from_array(&env, [(0u64, false)])
+    );
+}
+
+#[test]
+fn test_sub_quorum_reveals_reselect_panel() {
+    let env = Env::default();
+    let (client, _, dispute_id, jurors) = setup_voting_dispute(&env, 14);
+    let salt = test_salt(&env);
+    let stake = 500_0000000;
+
+    // Three of seven reveal, one short of a majority
+    let mut revealers = Vec::new(&env);
+    for i in 0..3 {
+        revealers.push_back(jurors.get(i).unwrap());
+    }
+    commit_all(
+        &client,
+        dispute_id,
+        &revealers,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        &client,
+        dispute_id,
+        &revealers,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+    client.tally_votes(&dispute_id);
+
+    // The dispute is not resolved; a fresh panel votes from scratch
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::Voting);
+    assert_eq!(dispute.resolution, DisputeResolution::NoRes);
+    let new_panel = client.get_juror_assignments(&dispute_id);
+    assert_eq!(new_panel.len(), shared::JURY_SIZE);
+    for juror in new_panel.iter() {
+        assert!(!jurors.contains(&juror));
+    }
+
+    for juror in jurors.iter() {
+        let info = juror_info(&env, &client, &juror);
+        assert_eq!(info.active_disputes, 0);
+        assert_eq!(info.successful_votes, 0);
+        if revealers.contains(&juror) {
+            assert_eq!(info.staked_amount, stake);
+            assert_eq!(info.missed_votes, 0);
+        } else {
+            assert_eq!(info.staked_amount, stake - shared::MIN_JUROR_STAKE / 10);
+            assert_eq!(info.missed_votes, 1);
+        }
+    }
+
+    // A majority of the fresh panel revealing resolves the dispute
+    tally_unanimous(&env, &client, dispute_id, &new_panel);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, shared::types::DisputeStatus::Resolved);
+    assert_eq!(dispute.resolution, DisputeResolution::RelFunds);
+}
+
+#[test]
+fn test_juror_rewards_weighted_by_stake() {
+    let env = Env::default();
+    let (client, admin, _, dispute_id) = setup_pending_dispute(&env, 3);
+    client.configure_dispute_params(&admin, &small_dispute_config());
+    client.select_jury(&dispute_id);
+    let panel = client.get_juror_assignments(&dispute_id);
+    let (large, small, minority) = (
+        panel.get(0).unwrap(),
+        panel.get(1).unwrap(),
+        panel.get(2).unwrap(),
+    );
+
+    // The larger staker holds twice the stake of the smaller one
+    env.as_contract(&client.address, || {
+        for (juror, stake) in [(&large, 200_0000001i128), (&small, 100_0000000)] {
+            let mut info = crate::storage::get_juror(&env, juror).unwrap();
+            info.staked_amount = stake;
+            crate::storage::set_juror(&env, juror, &info);
+        }
+    });
+
+    let salt = test_salt(&env);
+    let mut majority = Vec::new(&env);
+    majority.push_back(large.clone());
+    majority.push_back(small.clone());
+    let mut outvoted = Vec::new(&env);
+    outvoted.push_back(minority.clone());
+    commit_all(
+        &client,
+        dispute_id,
+        &majority,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    commit_all(
+        &client,
+        dispute_id,
+        &outvoted,
+        DisputeResolution::RefBackers,
+        &salt,
+    );
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + shared::VOTING_COMMIT_PERIOD + 1);
+    reveal_all(
+        &client,
+        dispute_id,
+        &majority,
+        DisputeResolution::RelFunds,
+        &salt,
+    );
+    reveal_all(
+        &client,
+        dispute_id,
+        &outvoted,
+        DisputeResolution::RefBackers,
+        &salt,
+    );
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + shared::VOTING_REVEAL_PERIOD + shared::REVEAL_GRACE_PERIOD + 1,
+    );
+
+    let pool_before = client.get_dispute_stats().fee_pool;
+    client.tally_votes(&dispute_id);
+
+    // Half the minority slash compensates the creator, the rest joins the pool,
+    // which is split 2:1 by stake
+    let pool = pool_before + 50_0000000;
+    let total_stake = 300_0000001i128;
+    let large_reward = pool * 200_0000001 / total_stake;
+    let small_reward = pool * 100_0000000 / total_stake;
+    assert_eq!(
+        juror_info(&env, &client, &large).rewards_accrued,
+        large_reward
+    );
+    assert_eq!(
+        juror_info(&env, &client, &small).rewards_accrued,
+        small_reward
+    );
+    assert!(large_reward >= 2 * small_reward);
+
+    // Rounding leftovers stay in the pool, so every unit is accounted for
+    let leftover = client.get_dispute_stats().fee_pool;
+    assert!(leftover > 0);
+    assert_eq!(large_reward + small_reward + leftover, pool);
+}
+
+#[test]
+fn test_dispute_only_within_contest_window() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    for i in 0..2u8 {
+        client.create_milestone(&1, &BytesN::from_array(&env, &[i; 32]), &400);
+        client.submit_milestone(
+            &1,
+            &(i as u64),
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &test_uri(&env),
+        );
+    }
+    let submitted_at = env.ledger().timestamp();
+    assert_eq!(client.get_milestone(&1, &0).submitted_at, submitted_at);
+
+    // Still open on the last second of the window
+    env.ledger()
+        .set_timestamp(submitted_at + shared::constants::CONTEST_WINDOW_SECS);
+    client.initiate_dispute(&1, &0, &creator);
+
+    env.ledger()
+        .set_timestamp(submitted_at + shared::constants::CONTEST_WINDOW_SECS + 1);
+    assert_eq!(
+        client.try_initiate_dispute(&1, &1, &creator),
+        Err(Ok(Error::MstoneContest))
+    );
+}
+
+#[test]
+fn test_auto_rejected_milestone_can_be_disputed() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &400);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    let submitted_at = env.ledger().timestamp();
+
+    // Finalized well after the contest window measured from submission has closed
+    let rejected_at = submitted_at + shared::constants::CONTEST_WINDOW_SECS + 100;
+    env.ledger().set_timestamp(rejected_at);
+    assert_eq!(
+        client.finalize_milestone_vote(&1, &0),
+        VoteOutcome::Rejected
+    );
+    assert_eq!(client.get_milestone(&1, &0).rejected_at, rejected_at);
+
+    env.ledger()
+        .set_timestamp(rejected_at + shared::constants::CONTEST_WINDOW_SECS + 1);
+    assert_eq!(
+        client.try_initiate_dispute(&1, &0, &creator),
+        Err(Ok(Error::MstoneContest))
+    );
+    env.ledger()
+        .set_timestamp(rejected_at + shared::constants::CONTEST_WINDOW_SECS);
+    let dispute_id = client.initiate_dispute(&1, &0, &creator);
+    assert_eq!(client.get_dispute(&dispute_id).milestone_id, 0);
+}
+
+#[test]
+fn test_abandon_milestone_frees_allocation() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &600);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+    client.vote_milestone(&1, &0, &validators.get(0).unwrap(), &true);
+    assert_eq!(
+        client.try_create_milestone(&1, &BytesN::from_array(&env, &[2u8; 32]), &500),
+        Err(Ok(Error::EscrowInsuf))
+    );
+
+    client.abandon_milestone(&1, &0);
+    let milestone = client.get_milestone(&1, &0);
+    assert_eq!(milestone.status, MilestoneStatus::Cancelled);
+    assert_eq!(client.get_total_milestone_amount(&1), 0);
+    assert_eq!(
+        client.get_validator_votes(&1, &validators.get(0).unwrap()),
+        Vec::new(&env)
+    );
+
+    // The freed amount funds a new milestone; the abandoned one cannot come back
+    client.create_milestone(&1, &BytesN::from_array(&env, &[2u8; 32]), &500);
+    assert_eq!(
+        client.try_abandon_milestone(&1, &0),
+        Err(Ok(Error::MstoneInv))
+    );
+    assert_eq!(
+        client.try_vote_milestone(&1, &0, &validators.get(1).unwrap(), &true),
+        Err(Ok(Error::MstoneInv))
+    );
+}
+
+#[test]
+fn test_abandon_disputed_milestone_fails() {
+    let (env, creator, token, _, validators) = create_test_env();
+    env.mock_all_auths();
+    let client = create_client(&env);
+    client.initialize(
+        &1,
+        &creator,
+        &token,
+        &validators,
+        &DEFAULT_THRESHOLD,
+        &0,
+        &0,
+        &0,
+        &0,
+    );
+    client.deposit(&1, &1000);
+    client.create_milestone(&1, &BytesN::from_array(&env, &[1u8; 32]), &600);
+    client.submit_milestone(
+        &1,
+        &0,
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &test_uri(&env),
+    );
+
+    client.initiate_dispute(&1, &0, &creator);
+    assert_eq!(
+        client.try_abandon_milestone(&1, &0),
+        Err(Ok(Error::MstoneInv))
+    );
+    assert_eq!(
+        client.get_milestone(&1, &0).status,
+        MilestoneStatus::Submitted
+    );
+}
@@ -1,6 +1,6 @@
 use shared::errors::Error;
 use shared::types::EscrowInfo;
-use soroban_sdk::Address;
+use soroban_sdk::{Address, Vec};
 
 /// Validate that an address is a validator in the escrow
 pub fn validate_validator(escrow: &EscrowInfo, validator: &Address) -> Result<(), Error> {
@@ -10,3 +10,13 @@ pub fn validate_validator(escrow: &EscrowInfo, validator: &Address) -> Result<()
         Err(Error::NotValidator)
     }
 }
+
+/// Validate that no address appears twice in a validator set
+pub fn validate_unique_validators(validators: &Vec<Address>) -> Result<(), Error> {
+    for (i, validator) in validators.iter().enumerate() {
+        if validators.first_index_of(&validator) != Some(i as u32) {
+            return Err(Error::InvInput);
+        }
+    }
+    Ok(())
+}
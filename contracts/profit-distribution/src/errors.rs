@@ -1,3 +1,4 @@
+use shared::errors::Error;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -13,4 +14,29 @@ pub enum ContractError {
     AlreadyClaimed = 7,
     Unauthorized = 8,
     InvalidAmount = 9,
+    ClaimBelowFee = 10,
+    InvestorExists = 11,
+    RegistrationNotFinalized = 12,
+    RegistrationFinalized = 13,
+    TokenMismatch = 14,
+    AllowanceExceeded = 15,
+    ProjectNotFound = 16,
+    ClawbackUnavailable = 17,
+    ContractPaused = 18,
+    UpgradeNotScheduled = 19,
+    UpgradeTooEarly = 20,
+    UpgradeRequiresPause = 21,
+}
+
+/// Map the errors returned by the shared upgrade flow
+impl From<Error> for ContractError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotInit => ContractError::NotInitialized,
+            Error::UpgNotSched => ContractError::UpgradeNotScheduled,
+            Error::UpgTooEarly => ContractError::UpgradeTooEarly,
+            Error::UpgReqPause => ContractError::UpgradeRequiresPause,
+            _ => ContractError::Unauthorized,
+        }
+    }
 }
@@ -2,22 +2,131 @@ use soroban_sdk::{contracttype, Address, Env};
 
 #[contracttype]
 pub enum EventType {
-    ProfitDeposited(u64, i128),          // project_id, amount
-    DividendClaimed(u64, Address, i128), // project_id, investor, amount
+    ProfitDeposited(u64, i128),                  // project_id, amount
+    DividendClaimed(u64, Address, i128),         // project_id, investor, amount
+    ShareRenounced(u64, Address, u32),           // project_id, investor, share_percentage
+    DepositFailed(u64, i128),                    // project_id, amount skipped by a batch deposit
+    DividendReinvested(u64, u64, Address, i128), // from_project, to_project, investor, amount
+    DepositClawedBack(u64, Address, i128),       // project_id, depositor, amount returned
+    ContractPaused(Address),                     // admin
+    ContractResumed(Address),                    // admin
 }
 
-pub fn emit_deposit_event(env: &Env, project_id: u64, amount: i128) {
+/// Data published with `ProfitDeposited`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfitDepositEvent {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    /// Project's accumulated profit per share after this deposit
+    pub acc_profit_per_share: i128,
+    pub timestamp: u64,
+}
+
+/// Data published with `DividendClaimed`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DividendClaimEvent {
+    pub project_id: u64,
+    pub token: Address,
+    pub investor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_deposit_event(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    amount: i128,
+    acc_profit_per_share: i128,
+) {
+    let data = ProfitDepositEvent {
+        project_id,
+        token: token.clone(),
+        amount,
+        acc_profit_per_share,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events()
+        .publish((EventType::ProfitDeposited(project_id, amount),), data);
+}
+
+pub fn emit_deposit_failed_event(env: &Env, project_id: u64, amount: i128) {
     env.events()
-        .publish((EventType::ProfitDeposited(project_id, amount),), ());
+        .publish((EventType::DepositFailed(project_id, amount),), ());
 }
 
-pub fn emit_claim_event(env: &Env, project_id: u64, investor: &Address, amount: i128) {
+pub fn emit_claim_event(
+    env: &Env,
+    project_id: u64,
+    token: &Address,
+    investor: &Address,
+    amount: i128,
+) {
+    let data = DividendClaimEvent {
+        project_id,
+        token: token.clone(),
+        investor: investor.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
     env.events().publish(
         (EventType::DividendClaimed(
             project_id,
             investor.clone(),
             amount,
         ),),
+        data,
+    );
+}
+
+pub fn emit_renounce_event(env: &Env, project_id: u64, investor: &Address, share_percentage: u32) {
+    env.events().publish(
+        (EventType::ShareRenounced(
+            project_id,
+            investor.clone(),
+            share_percentage,
+        ),),
+        (),
+    );
+}
+
+pub fn emit_clawback_event(env: &Env, project_id: u64, depositor: &Address, amount: i128) {
+    env.events().publish(
+        (EventType::DepositClawedBack(
+            project_id,
+            depositor.clone(),
+            amount,
+        ),),
         (),
     );
 }
+
+pub fn emit_reinvest_event(
+    env: &Env,
+    from_project: u64,
+    to_project: u64,
+    investor: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (EventType::DividendReinvested(
+            from_project,
+            to_project,
+            investor.clone(),
+            amount,
+        ),),
+        (),
+    );
+}
+
+pub fn emit_pause_event(env: &Env, admin: &Address, paused: bool) {
+    let event = if paused {
+        EventType::ContractPaused(admin.clone())
+    } else {
+        EventType::ContractResumed(admin.clone())
+    };
+    env.events().publish((event,), env.ledger().timestamp());
+}
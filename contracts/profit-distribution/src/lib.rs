@@ -6,7 +6,16 @@
 // - Dividend claiming mechanism
 
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contractmeta, token::TokenClient, Address, Env, Map};
+use shared::{
+    constants::MAX_BATCH_SIZE,
+    errors::Error,
+    math::pro_rata,
+    types::{BatchResult, PendingUpgrade},
+    upgradeable::{self, Upgradeable},
+};
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, token::TokenClient, Address, BytesN, Env, Map, Vec,
+};
 
 mod errors;
 mod events;
@@ -18,9 +27,12 @@ mod tests;
 
 use crate::{
     errors::ContractError,
-    events::{emit_claim_event, emit_deposit_event},
+    events::{
+        emit_claim_event, emit_clawback_event, emit_deposit_event, emit_deposit_failed_event,
+        emit_pause_event, emit_reinvest_event, emit_renounce_event,
+    },
     storage::*,
-    types::InvestorShare,
+    types::{InvestorShare, LastDeposit},
 };
 
 const PRECISION: i128 = 1_000_000_000_000;
@@ -40,6 +52,7 @@ impl ProfitDistribution {
         }
         admin.require_auth();
         set_admin(&env, &admin);
+        set_version(&env, 1);
         Ok(())
     }
 
@@ -59,10 +72,120 @@ impl ProfitDistribution {
         get_dev_fund_address(&env)
     }
 
-    /// Register the token used for project profits
+    /// Set the flat fee deducted from each dividend claim and routed to the Developer Fund (Admin only)
+    pub fn set_claim_fee(env: Env, admin: Address, fee: i128) -> Result<(), ContractError> {
+        let stored_admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+
+        if fee < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        // The fee has to land somewhere: require the treasury before enabling it
+        if fee > 0 && get_dev_fund_address(&env).is_none() {
+            return Err(ContractError::NotInitialized);
+        }
+
+        set_claim_fee(&env, fee);
+        Ok(())
+    }
+
+    /// Get the flat fee deducted from each dividend claim
+    pub fn get_claim_fee(env: Env) -> i128 {
+        get_claim_fee(&env)
+    }
+
+    /// Set who collects the unregistered share of a project's profits (Admin only)
+    ///
+    /// Once set, deposits are split over the full 100% and the portion not
+    /// covered by registered investors accrues to this recipient.
+    pub fn set_residual_recipient(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        let stored_admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+        set_residual_recipient(&env, project_id, &recipient);
+        Ok(())
+    }
+
+    /// Get the residual recipient of a project
+    pub fn get_residual_recipient(env: Env, project_id: u64) -> Option<Address> {
+        get_residual_recipient(&env, project_id)
+    }
+
+    /// Get the residual amount accrued and not yet claimed
+    pub fn get_residual_claimable(env: Env, project_id: u64) -> i128 {
+        get_residual_claimable(&env, project_id)
+    }
+
+    /// Allow the residual recipient to collect the unregistered share
+    pub fn claim_residual(
+        env: Env,
+        project_id: u64,
+        recipient: Address,
+    ) -> Result<i128, ContractError> {
+        recipient.require_auth();
+        require_not_paused(&env)?;
+
+        let stored_recipient =
+            get_residual_recipient(&env, project_id).ok_or(ContractError::NotInitialized)?;
+        if stored_recipient != recipient {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let amount = get_residual_claimable(&env, project_id);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+        set_residual_claimable(&env, project_id, 0);
+        remove_last_deposit(&env, project_id);
+
+        let token_address =
+            get_project_token(&env, project_id).ok_or(ContractError::NotInitialized)?;
+        let token_client = TokenClient::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+        let balance = get_project_balance(&env, project_id);
+        set_project_balance(&env, project_id, balance - amount);
+
+        emit_claim_event(&env, project_id, &token_address, &recipient, amount);
+        Ok(amount)
+    }
+
+    /// Create a project and register the token used for its profits (Admin only)
+    ///
+    /// Every other project entry point rejects ids that were not created here,
+    /// so a mistyped id cannot silently start a phantom project.
+    pub fn create_project(env: Env, project_id: u64, token: Address) -> Result<(), ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        if project_exists(&env, project_id) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        set_project_created(&env, project_id);
+        set_project_token(&env, project_id, &token);
+        Ok(())
+    }
+
+    /// Check whether a project has been created
+    pub fn project_exists(env: Env, project_id: u64) -> bool {
+        project_exists(&env, project_id)
+    }
+
+    /// Replace the token used for an existing project's profits
     pub fn set_token(env: Env, project_id: u64, token: Address) -> Result<(), ContractError> {
         let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
         admin.require_auth();
+        require_project(&env, project_id)?;
+        remove_last_deposit(&env, project_id);
         set_project_token(&env, project_id, &token);
         Ok(())
     }
@@ -75,15 +198,24 @@ impl ProfitDistribution {
     ) -> Result<(), ContractError> {
         let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
         admin.require_auth();
+        require_project(&env, project_id)?;
+
+        if is_registration_finalized(&env, project_id) {
+            return Err(ContractError::RegistrationFinalized);
+        }
 
         let mut total_shares: u32 = 0;
         let current_acc = get_acc_profit_per_share(&env, project_id);
+        let mut index = get_project_investors(&env, project_id);
 
         for (investor, share_percentage) in investors.iter() {
             if share_percentage == 0 {
                 return Err(ContractError::InvalidSharePercentage);
             }
             total_shares += share_percentage;
+            if !index.contains(&investor) {
+                index.push_back(investor.clone());
+            }
 
             let share = InvestorShare {
                 investor: investor.clone(),
@@ -100,59 +232,244 @@ impl ProfitDistribution {
         }
 
         set_total_shares(&env, project_id, total_shares);
+        set_project_investors(&env, project_id, &index);
+        Ok(())
+    }
+
+    /// Dry-run an investor map without writing anything
+    ///
+    /// Returns the summed shares and whether they make up exactly 10000 basis
+    /// points with no zero share, i.e. a full allocation `register_investors`
+    /// would accept.
+    pub fn validate_investors(_env: Env, investors: Map<Address, u32>) -> (u32, bool) {
+        let mut total_shares: u32 = 0;
+        let mut has_zero_share = false;
+        for (_, share_percentage) in investors.iter() {
+            has_zero_share |= share_percentage == 0;
+            total_shares = total_shares.saturating_add(share_percentage);
+        }
+        (total_shares, total_shares == 10000 && !has_zero_share)
+    }
+
+    /// Lock in a project's shares so it can start receiving profits (Admin only)
+    ///
+    /// Registered shares must total 10000 basis points, unless a residual
+    /// recipient collects the unregistered part. `register_investors` is closed
+    /// afterwards; backdated investors can still take up residual shares.
+    pub fn finalize_registration(env: Env, project_id: u64) -> Result<(), ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        if is_registration_finalized(&env, project_id) {
+            return Err(ContractError::RegistrationFinalized);
+        }
+        let total_shares = get_total_shares(&env, project_id).unwrap_or(0);
+        let fully_allocated = total_shares == 10000
+            || (total_shares > 0 && get_residual_recipient(&env, project_id).is_some());
+        if !fully_allocated {
+            return Err(ContractError::TotalSharesNot100);
+        }
+
+        set_registration_finalized(&env, project_id);
+        Ok(())
+    }
+
+    /// Check whether a project's registration has been finalized
+    pub fn is_registration_finalized(env: Env, project_id: u64) -> bool {
+        is_registration_finalized(&env, project_id)
+    }
+
+    /// Register one investor with a backdated accumulator baseline (Admin only)
+    ///
+    /// A baseline below the current accumulator grants a retroactive claim on
+    /// earlier deposits. It is paid from funds not owed to other investors,
+    /// drawing on the residual share first.
+    pub fn register_investor_with_baseline(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        share_percentage: u32,
+        baseline_acc: i128,
+    ) -> Result<(), ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+        require_project(&env, project_id)?;
+
+        if share_percentage == 0 {
+            return Err(ContractError::InvalidSharePercentage);
+        }
+        if get_investor_share(&env, project_id, &investor).is_some() {
+            return Err(ContractError::InvestorExists);
+        }
+        let total_shares = get_total_shares(&env, project_id).unwrap_or(0) + share_percentage;
+        if total_shares > 10000 {
+            return Err(ContractError::TotalSharesNot100);
+        }
+
+        let current_acc = get_acc_profit_per_share(&env, project_id);
+        if baseline_acc < 0 || baseline_acc > current_acc {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let share = InvestorShare {
+            investor: investor.clone(),
+            share_percentage,
+            accumulated_at_last_update: baseline_acc,
+            claimable_amount: 0,
+            total_claimed: 0,
+        };
+
+        // The retroactive part must be covered by funds nobody else is owed
+        let retroactive = pending_dividends(&share, current_acc)?;
+        if retroactive > Self::get_undistributed(env.clone(), project_id)? {
+            return Err(ContractError::InsufficientBalance);
+        }
+        let residual = get_residual_claimable(&env, project_id);
+        if residual > 0 {
+            set_residual_claimable(&env, project_id, residual - residual.min(retroactive));
+        }
+
+        add_investor_share(&env, project_id, &share, total_shares);
+        Ok(())
+    }
+
+    /// Register one more investor, accruing only from future deposits (Admin only)
+    ///
+    /// Unlike `register_investors`, existing investors are left untouched and the
+    /// stored total grows by `share_percentage`, which must keep it within 10000.
+    pub fn add_investor(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        share_percentage: u32,
+    ) -> Result<(), ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+        require_project(&env, project_id)?;
+
+        if share_percentage == 0 {
+            return Err(ContractError::InvalidSharePercentage);
+        }
+        if get_investor_share(&env, project_id, &investor).is_some() {
+            return Err(ContractError::InvestorExists);
+        }
+        let total_shares = get_total_shares(&env, project_id).unwrap_or(0) + share_percentage;
+        if total_shares > 10000 {
+            return Err(ContractError::TotalSharesNot100);
+        }
+
+        let share = InvestorShare {
+            investor,
+            share_percentage,
+            accumulated_at_last_update: get_acc_profit_per_share(&env, project_id),
+            claimable_amount: 0,
+            total_claimed: 0,
+        };
+        add_investor_share(&env, project_id, &share, total_shares);
         Ok(())
     }
 
     /// Deposit profits to be distributed among investors (with 5% tax to Developer Fund)
+    ///
+    /// Returns the project's accumulated profit per share after the deposit.
     pub fn deposit_profits(
         env: Env,
         project_id: u64,
         depositor: Address,
         amount: i128,
-    ) -> Result<(), ContractError> {
+    ) -> Result<i128, ContractError> {
         if amount <= 0 {
             return Err(ContractError::InvalidAmount);
         }
 
         depositor.require_auth();
+        require_not_paused(&env)?;
+        credit_deposit(&env, project_id, &depositor, amount, true)
+    }
+
+    /// Reverse a project's latest deposit and return it to the depositor (Admin only)
+    ///
+    /// Only possible while nothing has been paid out or re-shared since that
+    /// deposit, so undoing its accumulator increment cannot take back dividends
+    /// already settled. `amount` must match the deposited amount; the Developer
+    /// Fund tax already transferred is not recovered. Returns the amount sent back.
+    pub fn clawback_deposit(
+        env: Env,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+        require_project(&env, project_id)?;
 
         let token_address =
             get_project_token(&env, project_id).ok_or(ContractError::NotInitialized)?;
-        let total_shares =
-            get_total_shares(&env, project_id).ok_or(ContractError::NotInitialized)?;
-
-        if total_shares == 0 {
+        if token_address != token {
+            return Err(ContractError::TokenMismatch);
+        }
+        let deposit =
+            get_last_deposit(&env, project_id).ok_or(ContractError::ClawbackUnavailable)?;
+        if deposit.amount != amount {
             return Err(ContractError::InvalidAmount);
         }
 
-        // Calculate Developer Fund tax (5%)
-        let dev_fund_tax = (amount * DEV_FUND_TAX_BPS as i128) / 10_000;
-        let distribution_amount = amount - dev_fund_tax;
+        let acc = get_acc_profit_per_share(&env, project_id);
+        set_acc_profit_per_share(&env, project_id, acc - deposit.acc_delta);
+        if deposit.residual > 0 {
+            let claimable = get_residual_claimable(&env, project_id);
+            set_residual_claimable(&env, project_id, claimable - deposit.residual);
+        }
+        let balance = get_project_balance(&env, project_id);
+        set_project_balance(&env, project_id, balance - deposit.held);
+        remove_last_deposit(&env, project_id);
 
-        // Transfer tokens to contract
-        let token_client = TokenClient::new(&env, &token_address);
-        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+        TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &deposit.depositor,
+            &deposit.held,
+        );
+        emit_clawback_event(&env, project_id, &deposit.depositor, deposit.held);
+        Ok(deposit.held)
+    }
 
-        // Transfer tax to Developer Fund if configured
-        if let Some(dev_fund) = get_dev_fund_address(&env) {
-            token_client.transfer(&env.current_contract_address(), &dev_fund, &dev_fund_tax);
-            // Emit developer fund tax event
-            env.events().publish(
-                (soroban_sdk::symbol_short!("dev_tax"),),
-                (project_id, dev_fund_tax, dev_fund),
-            );
+    /// Deposit profits into several projects with a single authorization
+    ///
+    /// Items with a non-positive amount, or whose project was never created, has
+    /// no registered shares or an unfinalized registration, are skipped and
+    /// reported as failed.
+    pub fn batch_deposit_profits(
+        env: Env,
+        depositor: Address,
+        deposits: Vec<(u64, i128)>,
+    ) -> Result<BatchResult, ContractError> {
+        if deposits.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
         }
 
-        // Update global accumulated profit (only distribution amount)
-        let current_acc = get_acc_profit_per_share(&env, project_id);
-        let delta = (distribution_amount
-            .checked_mul(PRECISION)
-            .ok_or(ContractError::InvalidAmount)?)
-            / (total_shares as i128);
-        set_acc_profit_per_share(&env, project_id, current_acc + delta);
+        depositor.require_auth();
+        require_not_paused(&env)?;
 
-        emit_deposit_event(&env, project_id, distribution_amount);
-        Ok(())
+        let mut result = BatchResult {
+            succeeded: 0,
+            failed: Vec::new(&env),
+        };
+        for (project_id, amount) in deposits.iter() {
+            let credited = if amount <= 0 {
+                Err(ContractError::InvalidAmount)
+            } else {
+                credit_deposit(&env, project_id, &depositor, amount, true)
+            };
+            match credited {
+                Ok(_) => result.succeeded += 1,
+                Err(_) => {
+                    result.failed.push_back(project_id);
+                    emit_deposit_failed_event(&env, project_id, amount);
+                }
+            }
+        }
+
+        Ok(result)
     }
 
     /// Allow an investor to claim their dividends
@@ -162,36 +479,234 @@ impl ProfitDistribution {
         investor: Address,
     ) -> Result<i128, ContractError> {
         investor.require_auth();
+        require_not_paused(&env)?;
 
+        let (token_address, payout) = settle_claim(&env, project_id, &investor, i128::MAX)?;
+
+        // Transfer funds
+        let token_client = TokenClient::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &investor, &payout);
+
+        emit_claim_event(&env, project_id, &token_address, &investor, payout);
+        Ok(payout)
+    }
+
+    /// Allow `spender` to trigger claims for an investor, up to `max_amount` in payouts
+    ///
+    /// Replaces any previous allowance; 0 revokes it. Claimed funds always go
+    /// to the investor.
+    pub fn approve_claim(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        spender: Address,
+        max_amount: i128,
+    ) -> Result<(), ContractError> {
+        investor.require_auth();
+
+        if max_amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        get_investor_share(&env, project_id, &investor).ok_or(ContractError::Unauthorized)?;
+
+        set_claim_allowance(&env, project_id, &investor, &spender, max_amount);
+        Ok(())
+    }
+
+    /// Get the payout a spender may still claim for an investor
+    pub fn get_claim_allowance(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        spender: Address,
+    ) -> i128 {
+        get_claim_allowance(&env, project_id, &investor, &spender)
+    }
+
+    /// Claim an investor's dividends on their behalf, within the spender's allowance
+    ///
+    /// Pays the investor at most the remaining allowance and reduces it by the
+    /// payout; dividends above it stay claimable.
+    pub fn claim_from_allowance(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        spender: Address,
+    ) -> Result<i128, ContractError> {
+        spender.require_auth();
+        require_not_paused(&env)?;
+
+        let allowance = get_claim_allowance(&env, project_id, &investor, &spender);
+        if allowance <= 0 {
+            return Err(ContractError::AllowanceExceeded);
+        }
+
+        let (token_address, payout) = settle_claim(&env, project_id, &investor, allowance)?;
+        set_claim_allowance(&env, project_id, &investor, &spender, allowance - payout);
+
+        let token_client = TokenClient::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &investor, &payout);
+
+        emit_claim_event(&env, project_id, &token_address, &investor, payout);
+        Ok(payout)
+    }
+
+    /// Claim an investor's dividends in every token the project pays out
+    ///
+    /// Returns the `(token, amount)` pairs paid. Tokens with nothing claimable
+    /// (or not more than the claim fee) are skipped instead of failing the call.
+    /// A project currently pays out in its single registered token, so at most
+    /// one pair is returned.
+    pub fn claim_all_tokens(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+    ) -> Result<Vec<(Address, i128)>, ContractError> {
+        investor.require_auth();
+        require_not_paused(&env)?;
+
+        let mut claimed = Vec::new(&env);
+        let (token_address, payout) = match settle_claim(&env, project_id, &investor, i128::MAX) {
+            Ok(settled) => settled,
+            Err(ContractError::NothingToClaim | ContractError::ClaimBelowFee) => {
+                return Ok(claimed)
+            }
+            Err(err) => return Err(err),
+        };
+
+        let token_client = TokenClient::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &investor, &payout);
+        emit_claim_event(&env, project_id, &token_address, &investor, payout);
+
+        claimed.push_back((token_address, payout));
+        Ok(claimed)
+    }
+
+    /// Claim an investor's dividends from one project and deposit them into another
+    ///
+    /// The payout never leaves the contract: it is moved from `from_project`'s
+    /// balance straight into a deposit for `to_project` with the investor as
+    /// depositor, so the usual claim fee and Developer Fund tax both apply.
+    pub fn reinvest_dividends(
+        env: Env,
+        from_project: u64,
+        to_project: u64,
+        investor: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        investor.require_auth();
+        require_not_paused(&env)?;
+
+        let from_token =
+            get_project_token(&env, from_project).ok_or(ContractError::NotInitialized)?;
+        let to_token = get_project_token(&env, to_project).ok_or(ContractError::NotInitialized)?;
+        if from_project == to_project || from_token != token || to_token != token {
+            return Err(ContractError::TokenMismatch);
+        }
+
+        let (_, payout) = settle_claim(&env, from_project, &investor, i128::MAX)?;
+        credit_deposit(&env, to_project, &investor, payout, false)?;
+
+        emit_reinvest_event(&env, from_project, to_project, &investor, payout);
+        Ok(payout)
+    }
+
+    /// Preview what `claim_dividends` would transfer to an investor right now
+    ///
+    /// Returns 0 when a claim would fail for lack of funds (nothing accrued, or not
+    /// more than the claim fee) and for any token other than the project's.
+    pub fn preview_claimable(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
         let token_address =
             get_project_token(&env, project_id).ok_or(ContractError::NotInitialized)?;
-        let mut share =
+        let share =
             get_investor_share(&env, project_id, &investor).ok_or(ContractError::Unauthorized)?;
+        if token != token_address {
+            return Ok(0);
+        }
 
         let current_acc = get_acc_profit_per_share(&env, project_id);
+        match claim_amounts(&env, &share, current_acc) {
+            Ok((total_claimable, claim_fee)) => Ok(total_claimable - claim_fee),
+            Err(ContractError::NothingToClaim | ContractError::ClaimBelowFee) => Ok(0),
+            Err(err) => Err(err),
+        }
+    }
 
-        // Calculate pending amount
-        let pending = (share.share_percentage as i128
-            * (current_acc - share.accumulated_at_last_update))
-            / PRECISION;
-        let total_claimable = share.claimable_amount + pending;
+    /// Preview `preview_claimable` for several projects at once
+    ///
+    /// Returns each project's claimable amount, in the order given, and their sum.
+    /// Fails like `preview_claimable` for any project the investor has no share in.
+    pub fn preview_all(
+        env: Env,
+        project_ids: Vec<u64>,
+        investor: Address,
+        token: Address,
+    ) -> Result<(Vec<(u64, i128)>, i128), ContractError> {
+        if project_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidAmount);
+        }
 
-        if total_claimable <= 0 {
-            return Err(ContractError::NothingToClaim);
+        let mut previews = Vec::new(&env);
+        let mut total: i128 = 0;
+        for project_id in project_ids.iter() {
+            let claimable =
+                Self::preview_claimable(env.clone(), project_id, investor.clone(), token.clone())?;
+            total = total
+                .checked_add(claimable)
+                .ok_or(ContractError::InvalidAmount)?;
+            previews.push_back((project_id, claimable));
         }
+        Ok((previews, total))
+    }
 
-        // Update user state
-        share.claimable_amount = 0;
-        share.accumulated_at_last_update = current_acc;
-        share.total_claimed += total_claimable;
-        set_investor_share(&env, project_id, &investor, &share);
+    /// Give an investor's share back to the pool
+    ///
+    /// Dividends accrued up to now are paid out in full (no claim fee), then the
+    /// share is removed so future deposits are split among the remaining investors.
+    pub fn renounce_share(
+        env: Env,
+        project_id: u64,
+        investor: Address,
+    ) -> Result<i128, ContractError> {
+        investor.require_auth();
+        require_not_paused(&env)?;
 
-        // Transfer funds
-        let token_client = TokenClient::new(&env, &token_address);
-        token_client.transfer(&env.current_contract_address(), &investor, &total_claimable);
+        let share =
+            get_investor_share(&env, project_id, &investor).ok_or(ContractError::Unauthorized)?;
+        let total_shares =
+            get_total_shares(&env, project_id).ok_or(ContractError::NotInitialized)?;
 
-        emit_claim_event(&env, project_id, &investor, total_claimable);
-        Ok(total_claimable)
+        let current_acc = get_acc_profit_per_share(&env, project_id);
+        let pending = pending_dividends(&share, current_acc)?;
+        let accrued = share.claimable_amount + pending;
+
+        remove_investor_share(&env, project_id, &investor);
+        remove_last_deposit(&env, project_id);
+        set_total_shares(&env, project_id, total_shares - share.share_percentage);
+        let mut index = get_project_investors(&env, project_id);
+        if let Some(position) = index.first_index_of(&investor) {
+            index.remove(position);
+            set_project_investors(&env, project_id, &index);
+        }
+
+        // Settle what was already earned so nothing is stranded
+        if accrued > 0 {
+            let token_address =
+                get_project_token(&env, project_id).ok_or(ContractError::NotInitialized)?;
+            let token_client = TokenClient::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &investor, &accrued);
+            let balance = get_project_balance(&env, project_id);
+            set_project_balance(&env, project_id, balance - accrued);
+            emit_claim_event(&env, project_id, &token_address, &investor, accrued);
+        }
+
+        emit_renounce_event(&env, project_id, &investor, share.share_percentage);
+        Ok(accrued)
     }
 
     /// Get investor share information
@@ -204,16 +719,330 @@ impl ProfitDistribution {
             get_investor_share(&env, project_id, &investor).ok_or(ContractError::Unauthorized)?;
 
         let current_acc = get_acc_profit_per_share(&env, project_id);
-        let pending = (share.share_percentage as i128
-            * (current_acc - share.accumulated_at_last_update))
-            / PRECISION;
+        let pending = pending_dividends(&share, current_acc)?;
         share.claimable_amount += pending;
 
         Ok(share)
     }
 
+    /// Get the part of a project's held funds not owed to any investor
+    ///
+    /// This is the rounding dust left by deposits, any unclaimed residual share
+    /// and any tax kept while no Developer Fund is configured.
+    pub fn get_undistributed(env: Env, project_id: u64) -> Result<i128, ContractError> {
+        let current_acc = get_acc_profit_per_share(&env, project_id);
+        let mut owed: i128 = 0;
+        for investor in get_project_investors(&env, project_id).iter() {
+            if let Some(share) = get_investor_share(&env, project_id, &investor) {
+                owed += share.claimable_amount + pending_dividends(&share, current_acc)?;
+            }
+        }
+        Ok(get_project_balance(&env, project_id) - owed)
+    }
+
+    /// Refresh the storage TTL of a project and all of its investors (Admin only)
+    pub fn extend_project_ttl(env: Env, project_id: u64) -> Result<(), ContractError> {
+        let admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        extend_project_entries(&env, project_id);
+        extend_investor_shares(&env, project_id);
+        Ok(())
+    }
+
     /// Get contract admin
     pub fn get_admin(env: Env) -> Option<Address> {
         get_admin(&env)
     }
+
+    /// Get the live contract version, starting at 1 and bumped by each executed upgrade
+    pub fn get_version(env: Env) -> u32 {
+        get_version(&env)
+    }
+
+    /// Halt deposits and claims (Admin only)
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        let stored_admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+        set_paused(&env, true);
+        emit_pause_event(&env, &admin, true);
+        Ok(())
+    }
+
+    /// Let deposits and claims through again (Admin only)
+    pub fn resume(env: Env, admin: Address) -> Result<(), ContractError> {
+        let stored_admin = get_admin(&env).ok_or(ContractError::NotInitialized)?;
+        if stored_admin != admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+        set_paused(&env, false);
+        emit_pause_event(&env, &admin, false);
+        Ok(())
+    }
+
+    /// Returns whether the contract is currently paused
+    pub fn get_is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    // ---------- Upgrade (time-locked, admin only, requires pause) ----------
+    /// Schedule an upgrade. Admin only. Executable after UPGRADE_TIME_LOCK_SECS (48h).
+    pub fn schedule_upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        upgradeable::schedule_upgrade::<Self>(&env, &admin, new_wasm_hash)?;
+        Ok(())
+    }
+
+    /// Execute a scheduled upgrade. Admin only. Contract must be paused. Only after time-lock.
+    pub fn execute_upgrade(env: Env, admin: Address) -> Result<(), ContractError> {
+        upgradeable::execute_upgrade::<Self>(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Cancel a scheduled upgrade. Admin only.
+    pub fn cancel_upgrade(env: Env, admin: Address) -> Result<(), ContractError> {
+        Ok(upgradeable::cancel_upgrade::<Self>(&env, &admin)?)
+    }
+
+    /// Get pending upgrade info, if any.
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        get_pending_upgrade(&env)
+    }
+}
+
+impl Upgradeable for ProfitDistribution {
+    fn upgrade_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInit)
+    }
+
+    fn is_paused(env: &Env) -> bool {
+        is_paused(env)
+    }
+
+    fn pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+        get_pending_upgrade(env)
+    }
+
+    fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade) {
+        set_pending_upgrade(env, pending)
+    }
+
+    fn clear_pending_upgrade(env: &Env) {
+        clear_pending_upgrade(env)
+    }
+
+    fn version(env: &Env) -> u32 {
+        get_version(env)
+    }
+
+    fn set_version(env: &Env, version: u32) {
+        set_version(env, version)
+    }
+}
+
+/// Fail with `ContractPaused` while the admin has deposits and claims halted
+fn require_not_paused(env: &Env) -> Result<(), ContractError> {
+    if is_paused(env) {
+        return Err(ContractError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fail with `ProjectNotFound` unless the project went through `create_project`
+fn require_project(env: &Env, project_id: u64) -> Result<(), ContractError> {
+    if !project_exists(env, project_id) {
+        return Err(ContractError::ProjectNotFound);
+    }
+    Ok(())
+}
+
+/// Transfer a deposit in and credit it to a project's investors
+///
+/// Every check runs before the first write, so a failed deposit leaves nothing
+/// behind when `batch_deposit_profits` carries on with the next item. Without
+/// `pull_funds` the amount is already held by the contract and only moves
+/// between project balances. Returns the project's new accumulated profit per share.
+fn credit_deposit(
+    env: &Env,
+    project_id: u64,
+    depositor: &Address,
+    amount: i128,
+    pull_funds: bool,
+) -> Result<i128, ContractError> {
+    require_project(env, project_id)?;
+    let token_address = get_project_token(env, project_id).ok_or(ContractError::NotInitialized)?;
+    let total_shares = get_total_shares(env, project_id).ok_or(ContractError::NotInitialized)?;
+
+    if total_shares == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if !is_registration_finalized(env, project_id) {
+        return Err(ContractError::RegistrationNotFinalized);
+    }
+
+    // Calculate Developer Fund tax (5%)
+    let dev_fund_tax =
+        pro_rata(amount, DEV_FUND_TAX_BPS as i128, 10_000).ok_or(ContractError::InvalidAmount)?;
+    let distribution_amount = amount - dev_fund_tax;
+
+    // With a residual recipient, split over the full 100% and set aside the unregistered part
+    let has_residual_recipient = get_residual_recipient(env, project_id).is_some();
+    let (share_base, residual) = if has_residual_recipient {
+        let residual = pro_rata(distribution_amount, (10_000 - total_shares) as i128, 10_000)
+            .ok_or(ContractError::InvalidAmount)?;
+        (10_000, residual)
+    } else {
+        (total_shares, 0)
+    };
+
+    // Update global accumulated profit (only distribution amount)
+    let current_acc = get_acc_profit_per_share(env, project_id);
+    let delta = pro_rata(distribution_amount, PRECISION, share_base as i128)
+        .ok_or(ContractError::InvalidAmount)?;
+    let new_acc = current_acc
+        .checked_add(delta)
+        .ok_or(ContractError::InvalidAmount)?;
+
+    // Transfer tokens to contract
+    let token_client = TokenClient::new(env, &token_address);
+    if pull_funds {
+        token_client.transfer(depositor, &env.current_contract_address(), &amount);
+    }
+    let mut balance = get_project_balance(env, project_id) + amount;
+
+    // Transfer tax to Developer Fund if configured
+    if let Some(dev_fund) = get_dev_fund_address(env) {
+        token_client.transfer(&env.current_contract_address(), &dev_fund, &dev_fund_tax);
+        balance -= dev_fund_tax;
+        // Emit developer fund tax event
+        env.events().publish(
+            (soroban_sdk::symbol_short!("dev_tax"),),
+            (project_id, dev_fund_tax, dev_fund),
+        );
+    }
+    let held = balance - get_project_balance(env, project_id);
+    set_project_balance(env, project_id, balance);
+
+    if has_residual_recipient {
+        let claimable = get_residual_claimable(env, project_id);
+        set_residual_claimable(env, project_id, claimable + residual);
+    }
+    set_acc_profit_per_share(env, project_id, new_acc);
+
+    // Only deposits pulled from a depositor can be returned to them
+    if pull_funds {
+        let deposit = LastDeposit {
+            depositor: depositor.clone(),
+            amount,
+            held,
+            acc_delta: delta,
+            residual,
+        };
+        set_last_deposit(env, project_id, &deposit);
+    } else {
+        remove_last_deposit(env, project_id);
+    }
+
+    // Keep every investor's accrual alive, including those who rarely claim
+    extend_project_entries(env, project_id);
+    extend_investor_shares(env, project_id);
+
+    emit_deposit_event(
+        env,
+        project_id,
+        &token_address,
+        distribution_amount,
+        new_acc,
+    );
+    Ok(new_acc)
+}
+
+/// Store a newly registered share, the project's new total and its index entry
+fn add_investor_share(env: &Env, project_id: u64, share: &InvestorShare, total_shares: u32) {
+    remove_last_deposit(env, project_id);
+    set_investor_share(env, project_id, &share.investor, share);
+    set_total_shares(env, project_id, total_shares);
+    let mut index = get_project_investors(env, project_id);
+    index.push_back(share.investor.clone());
+    set_project_investors(env, project_id, &index);
+}
+
+/// Settle an investor's accrued dividends and route the claim fee to the treasury
+///
+/// Returns the project token and the payout left after the fee, at most
+/// `max_payout`; anything above it stays claimable. The payout is deducted
+/// from the project balance but not transferred.
+fn settle_claim(
+    env: &Env,
+    project_id: u64,
+    investor: &Address,
+    max_payout: i128,
+) -> Result<(Address, i128), ContractError> {
+    let token_address = get_project_token(env, project_id).ok_or(ContractError::NotInitialized)?;
+    let mut share =
+        get_investor_share(env, project_id, investor).ok_or(ContractError::Unauthorized)?;
+
+    let current_acc = get_acc_profit_per_share(env, project_id);
+    let (total_claimable, claim_fee) = claim_amounts(env, &share, current_acc)?;
+    let payout = (total_claimable - claim_fee).min(max_payout);
+    let settled = payout + claim_fee;
+
+    // Update user state
+    share.claimable_amount = total_claimable - settled;
+    share.accumulated_at_last_update = current_acc;
+    share.total_claimed += settled;
+    set_investor_share(env, project_id, investor, &share);
+    remove_last_deposit(env, project_id);
+    extend_project_entries(env, project_id);
+    extend_investor_share(env, project_id, investor);
+    let balance = get_project_balance(env, project_id);
+    set_project_balance(env, project_id, balance - settled);
+
+    // Route the claim fee to the treasury
+    if claim_fee > 0 {
+        let treasury = get_dev_fund_address(env).ok_or(ContractError::NotInitialized)?;
+        let token_client = TokenClient::new(env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &treasury, &claim_fee);
+        env.events().publish(
+            (soroban_sdk::symbol_short!("claim_fee"),),
+            (project_id, investor.clone(), claim_fee),
+        );
+    }
+
+    Ok((token_address, payout))
+}
+
+/// Amount a claim would settle for a share and the fee withheld from it
+fn claim_amounts(
+    env: &Env,
+    share: &InvestorShare,
+    current_acc: i128,
+) -> Result<(i128, i128), ContractError> {
+    let pending = pending_dividends(share, current_acc)?;
+    let total_claimable = share.claimable_amount + pending;
+
+    if total_claimable <= 0 {
+        return Err(ContractError::NothingToClaim);
+    }
+
+    let claim_fee = get_claim_fee(env);
+    if total_claimable <= claim_fee {
+        return Err(ContractError::ClaimBelowFee);
+    }
+    Ok((total_claimable, claim_fee))
+}
+
+/// Dividends accrued by a share since its last update, failing instead of wrapping on overflow
+fn pending_dividends(share: &InvestorShare, current_acc: i128) -> Result<i128, ContractError> {
+    let growth = current_acc
+        .checked_sub(share.accumulated_at_last_update)
+        .ok_or(ContractError::InvalidAmount)?;
+    pro_rata(growth, share.share_percentage as i128, PRECISION).ok_or(ContractError::InvalidAmount)
 }
@@ -1,6 +1,13 @@
-use soroban_sdk::{Address, Env};
+use shared::types::PendingUpgrade;
+use soroban_sdk::{Address, Env, Vec};
 
-use crate::types::{DataKey, InvestorShare};
+use crate::types::{DataKey, InvestorShare, LastDeposit};
+
+const DAY_IN_LEDGERS: u32 = 17280;
+/// Entries are extended once their TTL falls below this many ledgers
+pub const TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+/// TTL, in ledgers, an extended entry is given
+pub const TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
 
 pub fn set_project_token(env: &Env, project_id: u64, token: &Address) {
     env.storage()
@@ -26,6 +33,12 @@ pub fn get_investor_share(env: &Env, project_id: u64, investor: &Address) -> Opt
         .get(&DataKey::InvestorShare(project_id, investor.clone()))
 }
 
+pub fn remove_investor_share(env: &Env, project_id: u64, investor: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InvestorShare(project_id, investor.clone()));
+}
+
 pub fn set_total_shares(env: &Env, project_id: u64, total_shares: u32) {
     env.storage()
         .persistent()
@@ -59,6 +72,39 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Admin)
 }
 
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::Version, &version);
+}
+
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingUpgrade, pending);
+}
+
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().instance().get(&DataKey::PendingUpgrade)
+}
+
+pub fn clear_pending_upgrade(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingUpgrade);
+}
+
 pub fn set_dev_fund_address(env: &Env, dev_fund: &Address) {
     env.storage().instance().set(&DataKey::DevFund, dev_fund);
 }
@@ -66,3 +112,176 @@ pub fn set_dev_fund_address(env: &Env, dev_fund: &Address) {
 pub fn get_dev_fund_address(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::DevFund)
 }
+
+pub fn set_claim_fee(env: &Env, fee: i128) {
+    env.storage().instance().set(&DataKey::ClaimFee, &fee);
+}
+
+pub fn get_claim_fee(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimFee)
+        .unwrap_or(0)
+}
+
+pub fn set_residual_recipient(env: &Env, project_id: u64, recipient: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ResidualRecipient(project_id), recipient);
+}
+
+pub fn get_residual_recipient(env: &Env, project_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ResidualRecipient(project_id))
+}
+
+pub fn set_residual_claimable(env: &Env, project_id: u64, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ResidualClaimable(project_id), &amount);
+}
+
+pub fn get_residual_claimable(env: &Env, project_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ResidualClaimable(project_id))
+        .unwrap_or(0)
+}
+
+pub fn set_project_investors(env: &Env, project_id: u64, investors: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ProjectInvestors(project_id), investors);
+}
+
+pub fn get_project_investors(env: &Env, project_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProjectInvestors(project_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_project_balance(env: &Env, project_id: u64, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ProjectBalance(project_id), &balance);
+}
+
+pub fn get_project_balance(env: &Env, project_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProjectBalance(project_id))
+        .unwrap_or(0)
+}
+
+pub fn set_registration_finalized(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RegistrationFinalized(project_id), &true);
+}
+
+pub fn is_registration_finalized(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RegistrationFinalized(project_id))
+        .unwrap_or(false)
+}
+
+pub fn set_project_created(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ProjectCreated(project_id), &true);
+}
+
+pub fn project_exists(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProjectCreated(project_id))
+        .unwrap_or(false)
+}
+
+pub fn set_last_deposit(env: &Env, project_id: u64, deposit: &LastDeposit) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastDeposit(project_id), deposit);
+}
+
+pub fn get_last_deposit(env: &Env, project_id: u64) -> Option<LastDeposit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastDeposit(project_id))
+}
+
+pub fn remove_last_deposit(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LastDeposit(project_id));
+}
+
+pub fn set_claim_allowance(
+    env: &Env,
+    project_id: u64,
+    investor: &Address,
+    spender: &Address,
+    amount: i128,
+) {
+    let key = DataKey::ClaimAllowance(project_id, investor.clone(), spender.clone());
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_claim_allowance(
+    env: &Env,
+    project_id: u64,
+    investor: &Address,
+    spender: &Address,
+) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimAllowance(
+            project_id,
+            investor.clone(),
+            spender.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+}
+
+/// Extend the contract instance and a project's shared entries
+pub fn extend_project_entries(env: &Env, project_id: u64) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+    extend_persistent(env, &DataKey::ProjectToken(project_id));
+    extend_persistent(env, &DataKey::TotalShares(project_id));
+    extend_persistent(env, &DataKey::AccProfitPerShare(project_id));
+    extend_persistent(env, &DataKey::ProjectInvestors(project_id));
+    extend_persistent(env, &DataKey::ProjectBalance(project_id));
+    extend_persistent(env, &DataKey::ResidualRecipient(project_id));
+    extend_persistent(env, &DataKey::ResidualClaimable(project_id));
+    extend_persistent(env, &DataKey::RegistrationFinalized(project_id));
+    extend_persistent(env, &DataKey::ProjectCreated(project_id));
+}
+
+/// Extend one investor's share entry
+pub fn extend_investor_share(env: &Env, project_id: u64, investor: &Address) {
+    extend_persistent(env, &DataKey::InvestorShare(project_id, investor.clone()));
+}
+
+/// Extend every registered investor's share entry of a project
+pub fn extend_investor_shares(env: &Env, project_id: u64) {
+    for investor in get_project_investors(env, project_id).iter() {
+        extend_investor_share(env, project_id, &investor);
+    }
+}
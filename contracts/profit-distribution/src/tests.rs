@@ -1,7 +1,9 @@
 use super::*;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::token::StellarAssetClient;
-use soroban_sdk::{Address, Env, Map};
+use crate::events::{DividendClaimEvent, ProfitDepositEvent};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, FromVal, Map, Vec};
 
 fn setup_test() -> (Env, ProfitDistributionClient<'static>, Address, Address) {
     let env = Env::default();
@@ -22,6 +24,7 @@ fn test_initialize() {
     let (_env, client, admin, _) = setup_test();
     client.initialize(&admin);
     assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_version(), 1);
 }
 
 #[test]
@@ -30,7 +33,7 @@ fn test_full_distribution_flow() {
     let token_admin = StellarAssetClient::new(&env, &token);
 
     client.initialize(&admin);
-    client.set_token(&1, &token);
+    client.create_project(&1, &token);
 
     let investor1 = Address::generate(&env);
     let investor2 = Address::generate(&env);
@@ -40,6 +43,7 @@ fn test_full_distribution_flow() {
     investors.set(investor2.clone(), 4000); // 40%
 
     client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
 
     // Deposit profits (1000 - 5% tax = 950 distributed)
     let profit_provider = Address::generate(&env);
@@ -75,3 +79,960 @@ fn test_full_distribution_flow() {
         570
     ); // 380 + 40% of 475 = 380+190=570
 }
+
+fn setup_funded_project(
+    env: &Env,
+    client: &ProfitDistributionClient,
+    admin: &Address,
+    token: &Address,
+) -> (Address, Address) {
+    let token_admin = StellarAssetClient::new(env, token);
+
+    client.initialize(admin);
+    client.create_project(&1, token);
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+    let mut investors = Map::new(env);
+    investors.set(investor1.clone(), 6000);
+    investors.set(investor2.clone(), 4000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    let profit_provider = Address::generate(env);
+    token_admin.mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+
+    (investor1, investor2)
+}
+
+#[test]
+fn test_claim_fee_routed_to_treasury() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+
+    let treasury = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_dev_fund(&admin, &treasury);
+    client.set_claim_fee(&admin, &20);
+    assert_eq!(client.get_claim_fee(), 20);
+
+    client.create_project(&1, &token);
+    let investor = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor.clone(), 10000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+    let treasury_after_tax = token_client.balance(&treasury);
+    assert_eq!(treasury_after_tax, 50);
+
+    // 950 claimable, 20 fee
+    let paid = client.claim_dividends(&1, &investor);
+    assert_eq!(paid, 930);
+    assert_eq!(token_client.balance(&investor), 930);
+    assert_eq!(token_client.balance(&treasury), treasury_after_tax + 20);
+    assert_eq!(client.get_investor_share(&1, &investor).total_claimed, 950);
+}
+
+#[test]
+fn test_claim_below_fee_rejected() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    // investor1 has 570 claimable; a larger fee blocks the claim
+    client.set_dev_fund(&admin, &Address::generate(&env));
+    client.set_claim_fee(&admin, &600);
+
+    let result = client.try_claim_dividends(&1, &investor1);
+    assert_eq!(result, Err(Ok(ContractError::ClaimBelowFee)));
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        570
+    );
+}
+
+#[test]
+fn test_claim_fee_requires_treasury() {
+    let (_env, client, admin, _) = setup_test();
+    client.initialize(&admin);
+
+    let result = client.try_set_claim_fee(&admin, &10);
+    assert_eq!(result, Err(Ok(ContractError::NotInitialized)));
+}
+
+#[test]
+fn test_renounce_share_pays_accrued_and_grows_others() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, investor2) = setup_funded_project(&env, &client, &admin, &token);
+
+    // investor2 leaves with the 380 already accrued
+    let paid = client.renounce_share(&1, &investor2);
+    assert_eq!(paid, 380);
+    assert_eq!(token_client.balance(&investor2), 380);
+    assert_eq!(
+        client.try_get_investor_share(&1, &investor2),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    // investor1 now receives the whole next distribution (600 - 5% = 570)
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &600);
+    client.deposit_profits(&1, &profit_provider, &600);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        570 + 570
+    );
+}
+
+#[test]
+fn test_renounce_last_share_blocks_deposits() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, investor2) = setup_funded_project(&env, &client, &admin, &token);
+
+    client.renounce_share(&1, &investor1);
+    client.renounce_share(&1, &investor2);
+
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &100);
+    let result = client.try_deposit_profits(&1, &profit_provider, &100);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_residual_recipient_collects_unregistered_share() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+    let residual = Address::generate(&env);
+    client.set_residual_recipient(&admin, &1, &residual);
+
+    // Only 80% is allocated to investors
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor1.clone(), 5000);
+    investors.set(investor2.clone(), 3000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    // 1000 - 5% tax = 950 distributed
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+
+    assert_eq!(client.claim_dividends(&1, &investor1), 475);
+    assert_eq!(client.claim_dividends(&1, &investor2), 285);
+    assert_eq!(client.get_residual_claimable(&1), 190);
+
+    assert_eq!(client.claim_residual(&1, &residual), 190);
+    assert_eq!(token_client.balance(&residual), 190);
+    assert_eq!(
+        client.try_claim_residual(&1, &residual),
+        Err(Ok(ContractError::NothingToClaim))
+    );
+    assert_eq!(
+        client.try_claim_residual(&1, &investor1),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_undistributed_is_rounding_dust() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.set_dev_fund(&admin, &Address::generate(&env));
+    client.create_project(&1, &token);
+
+    let mut investors = Map::new(&env);
+    investors.set(Address::generate(&env), 3333);
+    investors.set(Address::generate(&env), 3333);
+    investors.set(Address::generate(&env), 3334);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    // 950 distributed; each third rounds down to 316, leaving 2 behind
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+    assert_eq!(client.get_undistributed(&1), 2);
+
+    for (investor, _) in investors.iter() {
+        client.claim_dividends(&1, &investor);
+    }
+    assert_eq!(client.get_undistributed(&1), 2);
+}
+
+#[test]
+fn test_undistributed_includes_residual_share() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.set_dev_fund(&admin, &Address::generate(&env));
+    client.create_project(&1, &token);
+    let residual = Address::generate(&env);
+    client.set_residual_recipient(&admin, &1, &residual);
+
+    let mut investors = Map::new(&env);
+    investors.set(Address::generate(&env), 5000);
+    investors.set(Address::generate(&env), 3000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+
+    // 20% of the 950 distributed is unassigned, with no rounding dust
+    assert_eq!(client.get_undistributed(&1), 190);
+    client.claim_residual(&1, &residual);
+    assert_eq!(client.get_undistributed(&1), 0);
+}
+
+#[test]
+fn test_accumulator_overflow_rejected() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+
+    // A single basis point of shares makes each unit of profit weigh 10000x
+    let leaving = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(Address::generate(&env), 1);
+    investors.set(leaving.clone(), 9999);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+    client.renounce_share(&1, &leaving);
+
+    let huge = 100_000_000_000_000_000_000_000_000; // 1e26
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &(2 * huge));
+    client.deposit_profits(&1, &profit_provider, &huge);
+
+    let result = client.try_deposit_profits(&1, &profit_provider, &huge);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_backdated_investor_claims_prior_deposits() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.set_dev_fund(&admin, &Address::generate(&env));
+    client.create_project(&1, &token);
+    client.set_residual_recipient(&admin, &1, &Address::generate(&env));
+
+    let early = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(early.clone(), 5000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    // 950 distributed: 475 to the early investor, 475 residual
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+
+    // A late investor backdated to the start shares in that deposit
+    let late = Address::generate(&env);
+    client.register_investor_with_baseline(&1, &late, &3000, &0);
+    assert_eq!(client.get_investor_share(&1, &late).claimable_amount, 285);
+    assert_eq!(client.get_residual_claimable(&1), 190);
+    assert_eq!(client.claim_dividends(&1, &late), 285);
+    assert_eq!(client.claim_dividends(&1, &early), 475);
+    assert_eq!(client.get_undistributed(&1), 190);
+}
+
+#[test]
+fn test_backdated_baseline_validated() {
+    let (env, client, admin, token) = setup_test();
+    let (_, investor2) = setup_funded_project(&env, &client, &admin, &token);
+
+    // Shares are already fully allocated
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_register_investor_with_baseline(&1, &late, &1, &0),
+        Err(Ok(ContractError::TotalSharesNot100))
+    );
+
+    // Free up shares; every distributed unit was paid out to the leaving investor or is owed
+    client.renounce_share(&1, &investor2);
+    let future_acc = i128::MAX;
+    assert_eq!(
+        client.try_register_investor_with_baseline(&1, &late, &1000, &future_acc),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_register_investor_with_baseline(&1, &late, &1000, &0),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+}
+
+/// Advance the ledger past the lifetime of an entry written with the minimum TTL
+fn advance_past_min_ttl(env: &Env) {
+    let min_ttl = env.ledger().get().min_persistent_entry_ttl;
+    env.ledger()
+        .with_mut(|li| li.sequence_number += min_ttl + 1);
+}
+
+#[test]
+fn test_deposit_keeps_shares_alive() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    advance_past_min_ttl(&env);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        570
+    );
+}
+
+#[test]
+fn test_extend_project_ttl_refreshes_registered_shares() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+    let investor = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor.clone(), 10000);
+    client.register_investors(&1, &investors);
+
+    client.extend_project_ttl(&1);
+    let ttl = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&types::DataKey::InvestorShare(1, investor.clone()))
+    });
+    assert_eq!(ttl, storage::TTL_EXTEND_TO);
+
+    advance_past_min_ttl(&env);
+    assert_eq!(
+        client.get_investor_share(&1, &investor).share_percentage,
+        10000
+    );
+}
+
+#[test]
+fn test_batch_deposit_skips_uninitialized_projects() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+    client.create_project(&2, &token);
+
+    let treasury = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&treasury, &2000);
+
+    // Project 2 has no investors, project 3 does not exist and the last item is empty
+    let mut deposits = Vec::new(&env);
+    deposits.push_back((1u64, 1000i128));
+    deposits.push_back((2, 500));
+    deposits.push_back((3, 500));
+    deposits.push_back((1, 0));
+    let result = client.batch_deposit_profits(&treasury, &deposits);
+
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(result.failed, soroban_sdk::vec![&env, 2u64, 3, 1]);
+    assert_eq!(token_client.balance(&treasury), 1000);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        1140
+    );
+}
+
+#[test]
+fn test_batch_deposit_respects_batch_size() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+
+    let mut deposits = Vec::new(&env);
+    for _ in 0..=MAX_BATCH_SIZE {
+        deposits.push_back((1u64, 1i128));
+    }
+    assert_eq!(
+        client.try_batch_deposit_profits(&Address::generate(&env), &deposits),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_preview_claimable_matches_claim() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, investor2) = setup_funded_project(&env, &client, &admin, &token);
+
+    let preview = client.preview_claimable(&1, &investor1, &token);
+    assert_eq!(preview, 570);
+    assert_eq!(client.claim_dividends(&1, &investor1), preview);
+    assert_eq!(client.preview_claimable(&1, &investor1, &token), 0);
+
+    // Accrual across several deposits, and nothing in a token the project does not pay
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &500);
+    client.deposit_profits(&1, &profit_provider, &500);
+    let other_token = Address::generate(&env);
+    assert_eq!(client.preview_claimable(&1, &investor2, &other_token), 0);
+    let preview = client.preview_claimable(&1, &investor2, &token);
+    assert_eq!(preview, 380 + 190);
+    assert_eq!(client.claim_dividends(&1, &investor2), preview);
+}
+
+#[test]
+fn test_preview_claimable_accounts_for_claim_fee() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.set_dev_fund(&admin, &Address::generate(&env));
+    client.set_claim_fee(&admin, &20);
+    client.create_project(&1, &token);
+    let investor = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor.clone(), 10000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1020);
+    client.deposit_profits(&1, &profit_provider, &1000);
+    let preview = client.preview_claimable(&1, &investor, &token);
+    assert_eq!(preview, 930);
+    assert_eq!(client.claim_dividends(&1, &investor), preview);
+
+    // 19 accrued does not cover the fee, so a claim would fail
+    client.deposit_profits(&1, &profit_provider, &20);
+    assert_eq!(client.preview_claimable(&1, &investor, &token), 0);
+    assert_eq!(
+        client.try_claim_dividends(&1, &investor),
+        Err(Ok(ContractError::ClaimBelowFee))
+    );
+}
+
+#[test]
+fn test_deposit_and_claim_publish_typed_events() {
+    let (env, client, admin, token) = setup_test();
+    env.ledger().set_timestamp(1_700_000_000);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let deposit = ProfitDepositEvent::from_val(&env, &data);
+    assert_eq!(
+        deposit,
+        ProfitDepositEvent {
+            project_id: 1,
+            token: token.clone(),
+            amount: 950,
+            acc_profit_per_share: 950 * PRECISION / 10_000,
+            timestamp: 1_700_000_000,
+        }
+    );
+
+    env.ledger().set_timestamp(1_700_000_500);
+    client.claim_dividends(&1, &investor1);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let claim = DividendClaimEvent::from_val(&env, &data);
+    assert_eq!(
+        claim,
+        DividendClaimEvent {
+            project_id: 1,
+            token,
+            investor: investor1,
+            amount: 570,
+            timestamp: env.ledger().timestamp(),
+        }
+    );
+}
+
+#[test]
+fn test_deposit_profits_returns_new_accumulator() {
+    let (env, client, admin, token) = setup_test();
+    setup_registered_project(&env, &client, &admin, &token);
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1500);
+
+    let first = client.deposit_profits(&1, &profit_provider, &1000);
+    let second = client.deposit_profits(&1, &profit_provider, &500);
+    assert_eq!(first, 950 * PRECISION / 10_000);
+    assert_eq!(second, first + 475 * PRECISION / 10_000);
+
+    let stored = env.as_contract(&client.address, || {
+        storage::get_acc_profit_per_share(&env, 1)
+    });
+    assert_eq!(second, stored);
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        ProfitDepositEvent::from_val(&env, &data).acc_profit_per_share,
+        stored
+    );
+}
+
+#[test]
+fn test_deposits_require_finalized_registration() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor1.clone(), 6000);
+    investors.set(investor2.clone(), 3000);
+    client.register_investors(&1, &investors);
+
+    // 90% allocated: neither depositing nor finalizing is possible yet
+    assert_eq!(
+        client.try_deposit_profits(&1, &profit_provider, &1000),
+        Err(Ok(ContractError::RegistrationNotFinalized))
+    );
+    assert_eq!(
+        client.try_finalize_registration(&1),
+        Err(Ok(ContractError::TotalSharesNot100))
+    );
+    assert!(!client.is_registration_finalized(&1));
+
+    investors.set(investor2.clone(), 4000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+    assert!(client.is_registration_finalized(&1));
+    client.deposit_profits(&1, &profit_provider, &1000);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        570
+    );
+
+    // Shares are locked in once finalized
+    assert_eq!(
+        client.try_register_investors(&1, &investors),
+        Err(Ok(ContractError::RegistrationFinalized))
+    );
+    assert_eq!(
+        client.try_finalize_registration(&1),
+        Err(Ok(ContractError::RegistrationFinalized))
+    );
+}
+
+#[test]
+fn test_reinvest_dividends_moves_funds_internally() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    client.create_project(&2, &token);
+    let investor3 = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor3.clone(), 10000);
+    client.register_investors(&2, &investors);
+    client.finalize_registration(&2);
+
+    let held = token_client.balance(&client.address);
+    assert_eq!(client.reinvest_dividends(&1, &2, &investor1, &token), 570);
+
+    // Nothing left the contract: 570 - 5% tax (28) accrues to project 2
+    assert_eq!(token_client.balance(&client.address), held);
+    assert_eq!(token_client.balance(&investor1), 0);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        0
+    );
+    assert_eq!(
+        client.get_investor_share(&2, &investor3).claimable_amount,
+        542
+    );
+
+    // Projects paid in different tokens cannot be linked
+    let other_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.create_project(&3, &other_token);
+    assert_eq!(
+        client.try_reinvest_dividends(&1, &3, &investor1, &token),
+        Err(Ok(ContractError::TokenMismatch))
+    );
+}
+
+#[test]
+fn test_add_investor_one_at_a_time() {
+    let (env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+    client.create_project(&1, &token);
+
+    let mut added = Vec::new(&env);
+    for bps in [5000u32, 3000, 2000] {
+        let investor = Address::generate(&env);
+        client.add_investor(&1, &investor, &bps);
+        added.push_back(investor);
+    }
+    assert_eq!(
+        client
+            .get_investor_share(&1, &added.get(1).unwrap())
+            .share_percentage,
+        3000
+    );
+
+    // Shares are already at 10000 and registered investors cannot be re-added
+    assert_eq!(
+        client.try_add_investor(&1, &Address::generate(&env), &1),
+        Err(Ok(ContractError::TotalSharesNot100))
+    );
+    assert_eq!(
+        client.try_add_investor(&1, &added.get(0).unwrap(), &1000),
+        Err(Ok(ContractError::InvestorExists))
+    );
+
+    client.finalize_registration(&1);
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &1000);
+    assert_eq!(
+        client
+            .get_investor_share(&1, &added.get(2).unwrap())
+            .claimable_amount,
+        190
+    );
+}
+
+#[test]
+fn test_claim_all_tokens_pays_and_skips_empty() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    let claimed = client.claim_all_tokens(&1, &investor1);
+    assert_eq!(claimed, Vec::from_array(&env, [(token.clone(), 570)]));
+    assert_eq!(token_client.balance(&investor1), 570);
+
+    // Nothing left to claim is not an error
+    assert_eq!(client.claim_all_tokens(&1, &investor1).len(), 0);
+}
+
+#[test]
+fn test_claim_allowance_consumed_across_claims() {
+    let (env, client, admin, token) = setup_test();
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+    let spender = Address::generate(&env);
+
+    assert_eq!(
+        client.try_claim_from_allowance(&1, &investor1, &spender),
+        Err(Ok(ContractError::AllowanceExceeded))
+    );
+    client.approve_claim(&1, &investor1, &spender, &400);
+
+    // The first claim is capped by the allowance; the rest stays claimable
+    assert_eq!(client.claim_from_allowance(&1, &investor1, &spender), 400);
+    assert_eq!(token_client.balance(&investor1), 400);
+    assert_eq!(token_client.balance(&spender), 0);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        170
+    );
+    assert_eq!(client.get_claim_allowance(&1, &investor1, &spender), 0);
+    assert_eq!(
+        client.try_claim_from_allowance(&1, &investor1, &spender),
+        Err(Ok(ContractError::AllowanceExceeded))
+    );
+
+    client.approve_claim(&1, &investor1, &spender, &1000);
+    assert_eq!(client.claim_from_allowance(&1, &investor1, &spender), 170);
+    assert_eq!(token_client.balance(&investor1), 570);
+    assert_eq!(client.get_claim_allowance(&1, &investor1, &spender), 830);
+}
+
+#[test]
+fn test_create_project_rejects_duplicates() {
+    let (_env, client, admin, token) = setup_test();
+    client.initialize(&admin);
+
+    assert!(!client.project_exists(&1));
+    client.create_project(&1, &token);
+    assert!(client.project_exists(&1));
+    assert_eq!(
+        client.try_create_project(&1, &token),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_missing_project_is_rejected() {
+    let (env, client, admin, token) = setup_test();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    client.initialize(&admin);
+
+    let investor = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor, 10000);
+    assert_eq!(
+        client.try_register_investors(&7, &investors),
+        Err(Ok(ContractError::ProjectNotFound))
+    );
+    assert_eq!(
+        client.try_set_token(&7, &token),
+        Err(Ok(ContractError::ProjectNotFound))
+    );
+
+    let profit_provider = Address::generate(&env);
+    token_admin.mint(&profit_provider, &1000);
+    assert_eq!(
+        client.try_deposit_profits(&7, &profit_provider, &1000),
+        Err(Ok(ContractError::ProjectNotFound))
+    );
+    assert!(!client.project_exists(&7));
+}
+
+#[test]
+fn test_validate_investors_dry_run() {
+    let (env, client, admin, _) = setup_test();
+    client.initialize(&admin);
+
+    let investor1 = Address::generate(&env);
+    let investor2 = Address::generate(&env);
+    let mut investors = Map::new(&env);
+    investors.set(investor1.clone(), 6000);
+    investors.set(investor2.clone(), 4000);
+    assert_eq!(client.validate_investors(&investors), (10000, true));
+
+    investors.set(investor2.clone(), 5000);
+    assert_eq!(client.validate_investors(&investors), (11000, false));
+
+    investors.set(investor2.clone(), 3000);
+    assert_eq!(client.validate_investors(&investors), (9000, false));
+
+    // Nothing is registered anywhere
+    assert!(client.try_get_investor_share(&1, &investor1).is_err());
+}
+
+#[test]
+fn test_preview_all_sums_across_projects() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+    let profit_provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&profit_provider, &600);
+
+    // Project 2 pays investor1 everything, project 3 half
+    let partner = Address::generate(&env);
+    for (project_id, share, deposit) in [(2u64, 10000u32, 200i128), (3, 5000, 400)] {
+        client.create_project(&project_id, &token);
+        let mut investors = Map::new(&env);
+        investors.set(investor1.clone(), share);
+        if share < 10000 {
+            investors.set(partner.clone(), 10000 - share);
+        }
+        client.register_investors(&project_id, &investors);
+        client.finalize_registration(&project_id);
+        client.deposit_profits(&project_id, &profit_provider, &deposit);
+    }
+
+    let ids = Vec::from_array(&env, [1u64, 2, 3]);
+    let (previews, total) = client.preview_all(&ids, &investor1, &token);
+    assert_eq!(
+        previews,
+        Vec::from_array(&env, [(1u64, 570i128), (2, 190), (3, 190)])
+    );
+    assert_eq!(total, 950);
+    for (project_id, claimable) in previews.iter() {
+        assert_eq!(
+            client.preview_claimable(&project_id, &investor1, &token),
+            claimable
+        );
+    }
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=MAX_BATCH_SIZE {
+        too_many.push_back(1u64);
+    }
+    assert_eq!(
+        client.try_preview_all(&too_many, &investor1, &token),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+fn setup_registered_project(
+    env: &Env,
+    client: &ProfitDistributionClient,
+    admin: &Address,
+    token: &Address,
+) -> (Address, Address) {
+    client.initialize(admin);
+    client.create_project(&1, token);
+
+    let investor1 = Address::generate(env);
+    let investor2 = Address::generate(env);
+    let mut investors = Map::new(env);
+    investors.set(investor1.clone(), 6000);
+    investors.set(investor2.clone(), 4000);
+    client.register_investors(&1, &investors);
+    client.finalize_registration(&1);
+    (investor1, investor2)
+}
+
+#[test]
+fn test_clawback_deposit_before_any_claim() {
+    let (env, client, admin, token) = setup_test();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    let token_client = TokenClient::new(&env, &token);
+    let (investor1, investor2) = setup_registered_project(&env, &client, &admin, &token);
+
+    let profit_provider = Address::generate(&env);
+    token_admin.mint(&profit_provider, &1500);
+    client.deposit_profits(&1, &profit_provider, &1000);
+    client.deposit_profits(&1, &profit_provider, &500);
+    assert_eq!(token_client.balance(&profit_provider), 0);
+
+    // Only the exact latest deposit can be reversed
+    assert_eq!(
+        client.try_clawback_deposit(&1, &token, &1000),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_clawback_deposit(&1, &Address::generate(&env), &500),
+        Err(Ok(ContractError::TokenMismatch))
+    );
+    assert_eq!(client.clawback_deposit(&1, &token, &500), 500);
+    assert_eq!(token_client.balance(&profit_provider), 500);
+    assert_eq!(
+        client.get_investor_share(&1, &investor1).claimable_amount,
+        570
+    );
+    assert_eq!(
+        client.get_investor_share(&1, &investor2).claimable_amount,
+        380
+    );
+
+    // The earlier deposit is no longer the latest one
+    assert_eq!(
+        client.try_clawback_deposit(&1, &token, &1000),
+        Err(Ok(ContractError::ClawbackUnavailable))
+    );
+}
+
+#[test]
+fn test_clawback_deposit_rejected_after_claim() {
+    let (env, client, admin, token) = setup_test();
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    client.claim_dividends(&1, &investor1);
+    assert_eq!(
+        client.try_clawback_deposit(&1, &token, &1000),
+        Err(Ok(ContractError::ClawbackUnavailable))
+    );
+    assert_eq!(
+        client.try_clawback_deposit(&7, &token, &1000),
+        Err(Ok(ContractError::ProjectNotFound))
+    );
+}
+
+#[test]
+fn test_pause_blocks_deposits_and_claims() {
+    let (env, client, admin, token) = setup_test();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    let (investor1, _) = setup_funded_project(&env, &client, &admin, &token);
+
+    client.pause(&admin);
+    assert!(client.get_is_paused());
+
+    let profit_provider = Address::generate(&env);
+    token_admin.mint(&profit_provider, &1000);
+    assert_eq!(
+        client.try_deposit_profits(&1, &profit_provider, &1000),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_claim_dividends(&1, &investor1),
+        Err(Ok(ContractError::ContractPaused))
+    );
+
+    client.resume(&admin);
+    assert!(!client.get_is_paused());
+    assert_eq!(client.claim_dividends(&1, &investor1), 570);
+}
+
+#[test]
+fn test_only_admin_can_pause() {
+    let (env, client, admin, _) = setup_test();
+    client.initialize(&admin);
+
+    let random = Address::generate(&env);
+    assert_eq!(
+        client.try_pause(&random),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(!client.get_is_paused());
+}
+
+#[test]
+fn test_schedule_and_cancel_upgrade() {
+    let (env, client, admin, _) = setup_test();
+    env.ledger().set_timestamp(1000);
+    client.initialize(&admin);
+
+    let wasm_hash = BytesN::from_array(&env, &[42u8; 32]);
+    client.schedule_upgrade(&admin, &wasm_hash);
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, wasm_hash);
+    assert_eq!(
+        pending.execute_not_before,
+        1000 + shared::UPGRADE_TIME_LOCK_SECS
+    );
+
+    client.cancel_upgrade(&admin);
+    assert!(client.get_pending_upgrade().is_none());
+    assert_eq!(
+        client.try_cancel_upgrade(&admin),
+        Err(Ok(ContractError::UpgradeNotScheduled))
+    );
+}
+
+#[test]
+fn test_only_admin_can_schedule_upgrade() {
+    let (env, client, admin, _) = setup_test();
+    client.initialize(&admin);
+
+    let random = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[42u8; 32]);
+    assert_eq!(
+        client.try_schedule_upgrade(&random, &wasm_hash),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(client.get_pending_upgrade().is_none());
+}
+
+#[test]
+fn test_version_bumped_by_upgrade() {
+    let (env, client, admin, _) = setup_test();
+    client.initialize(&admin);
+    assert_eq!(client.get_version(), 1);
+
+    // Stand-in for execute_upgrade, which bumps the version once the new wasm is live
+    env.as_contract(&client.address, || {
+        let version = <ProfitDistribution as Upgradeable>::version(&env);
+        <ProfitDistribution as Upgradeable>::set_version(&env, version + 1);
+    });
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_execute_upgrade_requires_pause_and_time_lock() {
+    let (env, client, admin, _) = setup_test();
+    env.ledger().set_timestamp(1000);
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(ContractError::UpgradeRequiresPause))
+    );
+    client.pause(&admin);
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(ContractError::UpgradeNotScheduled))
+    );
+
+    client.schedule_upgrade(&admin, &BytesN::from_array(&env, &[42u8; 32]));
+    assert_eq!(
+        client.try_execute_upgrade(&admin),
+        Err(Ok(ContractError::UpgradeTooEarly))
+    );
+    assert_eq!(client.get_version(), 1);
+}
@@ -10,6 +10,17 @@ pub struct InvestorShare {
     pub total_claimed: i128,
 }
 
+/// A project's latest deposit, kept so it can be reversed until anything is paid out
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LastDeposit {
+    pub depositor: Address,
+    pub amount: i128,    // amount the depositor sent
+    pub held: i128,      // part still held by the contract after the Developer Fund tax
+    pub acc_delta: i128, // increment added to the accumulator
+    pub residual: i128,  // part set aside for the residual recipient
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -19,4 +30,16 @@ pub enum DataKey {
     AccProfitPerShare(u64),      // project_id -> accumulated profit per share
     Admin,
     DevFund,                     // Developer Fund address
+    ClaimFee,                    // flat fee deducted from each claim
+    ResidualRecipient(u64),      // project_id -> recipient of the unregistered share
+    ResidualClaimable(u64),      // project_id -> residual accrued but not yet claimed
+    ProjectInvestors(u64),       // project_id -> investors holding a registered share
+    ProjectBalance(u64),         // project_id -> tokens held on behalf of the project
+    Version,                     // contract version, starting at 1
+    RegistrationFinalized(u64),  // project_id -> shares locked in at 100%
+    ClaimAllowance(u64, Address, Address), // project_id, investor, spender -> payout left
+    ProjectCreated(u64),         // project_id -> created through create_project
+    LastDeposit(u64),            // project_id -> latest deposit that can still be clawed back
+    Paused,                      // deposits and claims are halted
+    PendingUpgrade,              // upgrade scheduled through schedule_upgrade
 }
@@ -1,8 +1,12 @@
 #![no_std]
 
-use shared::constants::{REPUTATION_MAX, REPUTATION_MIN, REPUTATION_START};
+use shared::constants::{
+    MAX_BATCH_SIZE, MAX_MOMENTUM, MOMENTUM_DECAY_STEP, REPUTATION_DECAY_PERIOD,
+    REPUTATION_DECAY_PER_PERIOD, REPUTATION_MAX, REPUTATION_MIN, REPUTATION_START,
+};
 use shared::errors::Error;
-use shared::events::{BADGE_EARNED, REPUTATION_UPDATED, USER_REGISTERED};
+use shared::events::{BADGE_EARNED, REPUTATION_UPDATED, SCORE_CLAMPED, USER_REGISTERED};
+pub use shared::types::{BadgeType, ReputationProfile};
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
 
 /// Storage keys for the reputation contract
@@ -15,39 +19,40 @@ pub enum DataKey {
     Profile(Address),
     /// Contract initialization flag
     Initialized,
+    /// Index of every registered user, in registration order
+    AllUsers,
+    /// Expiry timestamp of a time-limited badge
+    BadgeExpiry(Address, BadgeType),
+    /// Presence marker for a badge in a user's profile, for constant-time checks
+    BadgeHeld(Address, BadgeType),
+    /// Contracts or accounts allowed to grant badges without admin auth
+    Granter(Address),
+    /// Score frozen for a user in a snapshot
+    SnapshotScore(u64, Address),
+    /// Index into `AllUsers` where a snapshot resumes; `u32::MAX` once complete
+    SnapshotCursor(u64),
+    /// Score below which a user is considered banned
+    BanThreshold,
+    /// Momentum built from recent score changes, bounded by MAX_MOMENTUM
+    Momentum(Address),
+    /// Timestamp up to which decay has been applied to a user
+    LastDecay(Address),
+    /// Badges an operator contract may award
+    OperatorBadges(Address),
+    /// Score scale chosen at initialization
+    ScoreBounds,
 }
 
-/// Badge types that can be awarded to users
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// Score scale of a deployment
+#[derive(Clone, Debug, PartialEq)]
 #[contracttype]
-#[repr(u32)]
-pub enum BadgeType {
-    /// Awarded for making contributions to projects
-    Contributor = 0,
-    /// Awarded to verified creators
-    VerifiedCreator = 1,
-    /// Awarded to top investors by volume
-    TopInvestor = 2,
-    /// Awarded for early platform adoption
-    EarlyAdopter = 3,
-    /// Awarded for completing first project
-    FirstProject = 4,
-    /// Awarded for successful milestone completion
-    MilestoneAchiever = 5,
-    /// Awarded for governance participation
-    GovernanceParticipant = 6,
-}
-
-/// User reputation profile
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[contracttype]
-pub struct ReputationProfile {
-    /// User's address
-    pub user: Address,
-    /// Reputation score (clamped between REPUTATION_MIN and REPUTATION_MAX)
-    pub score: i128,
-    /// List of badges earned by the user
-    pub badges: Vec<BadgeType>,
+pub struct ScoreBounds {
+    /// Lowest reachable score
+    pub min: i128,
+    /// Highest reachable score
+    pub max: i128,
+    /// Score given to newly registered users
+    pub start: i128,
 }
 
 #[contract]
@@ -64,23 +69,65 @@ impl ReputationContract {
     /// # Returns
     /// * `Result<(), Error>` - Ok if successful, Error if already initialized
     pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        Self::initialize_with_bounds(env, admin, REPUTATION_MIN, REPUTATION_MAX, REPUTATION_START)
+    }
+
+    /// Initialize the reputation contract with a custom score scale
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - The address that will have admin privileges
+    /// * `min` - Lowest reachable score
+    /// * `max` - Highest reachable score
+    /// * `start` - Score given to newly registered users
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful, InvInput unless `min < start < max`,
+    ///   AlreadyInit if already initialized
+    pub fn initialize_with_bounds(
+        env: Env,
+        admin: Address,
+        min: i128,
+        max: i128,
+        start: i128,
+    ) -> Result<(), Error> {
         // Check if already initialized
         if env.storage().instance().has(&DataKey::Initialized) {
             return Err(Error::AlreadyInit);
         }
 
+        if !(min < start && start < max) {
+            return Err(Error::InvInput);
+        }
+
         // Require admin authorization
         admin.require_auth();
 
         // Store admin address
         env.storage().instance().set(&DataKey::Admin, &admin);
 
+        // Store the score scale
+        env.storage()
+            .instance()
+            .set(&DataKey::ScoreBounds, &ScoreBounds { min, max, start });
+
         // Mark as initialized
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         Ok(())
     }
 
+    /// Get the score scale of this deployment
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `ScoreBounds` - The configured bounds, or the shared defaults
+    pub fn get_score_bounds(env: Env) -> ScoreBounds {
+        Self::score_bounds(&env)
+    }
+
     /// Register a new user with default reputation score
     ///
     /// # Arguments
@@ -108,7 +155,7 @@ impl ReputationContract {
         // Create profile with default score
         let profile = ReputationProfile {
             user: user.clone(),
-            score: REPUTATION_START,
+            score: Self::score_bounds(&env).start,
             badges: Vec::new(&env),
         };
 
@@ -117,6 +164,18 @@ impl ReputationContract {
             .persistent()
             .set(&DataKey::Profile(user.clone()), &profile);
 
+        // Start the decay clock
+        env.storage()
+            .persistent()
+            .set(&DataKey::LastDecay(user.clone()), &env.ledger().timestamp());
+
+        // Add to the user index
+        let mut all_users = Self::get_all_users(&env);
+        all_users.push_back(user.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllUsers, &all_users);
+
         // Emit registration event
         env.events().publish((USER_REGISTERED,), user);
 
@@ -140,12 +199,691 @@ impl ReputationContract {
         let admin = Self::get_admin(&env)?;
         admin.require_auth();
 
-        // Get existing profile
+        Self::apply_score_delta(&env, user, delta)
+    }
+
+    /// Update several users' scores in one call (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `updates` - (user, delta) pairs, at most MAX_BATCH_SIZE
+    /// * `atomic` - If true, any failing item aborts the whole batch;
+    ///   otherwise failing items are skipped
+    ///
+    /// # Returns
+    /// * `Result<u32, Error>` - Number of scores updated
+    pub fn batch_update_scores(
+        env: Env,
+        updates: Vec<(Address, i128)>,
+        atomic: bool,
+    ) -> Result<u32, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        if updates.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvInput);
+        }
+
+        let mut applied = 0;
+        for (user, delta) in updates.iter() {
+            match Self::apply_score_delta(&env, user, delta) {
+                Ok(_) => applied += 1,
+                // Returning the error reverts every write made so far
+                Err(err) if atomic => return Err(err),
+                Err(_) => {}
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Write the `BadgeHeld` markers missing from profiles stored before they existed (admin only)
+    ///
+    /// `has_badge` only reads the markers, so legacy profiles need this once
+    /// unless they are written again by an award or score update.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `users` - Users to migrate, at most MAX_BATCH_SIZE; unregistered users are skipped
+    ///
+    /// # Returns
+    /// * `Result<u32, Error>` - Number of markers written
+    pub fn migrate_badge_markers(env: Env, users: Vec<Address>) -> Result<u32, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        if users.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvInput);
+        }
+
+        let mut written = 0;
+        for user in users.iter() {
+            if let Ok(profile) = Self::get_profile_internal(&env, &user) {
+                written += Self::backfill_badge_markers(&env, &profile);
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// Award a badge to a user (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user to award the badge to
+    /// * `badge` - The type of badge to award
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful, Error if badge already awarded
+    pub fn award_badge(env: Env, user: Address, badge: BadgeType) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        Self::award_badge_internal(&env, &user, badge, None)
+    }
+
+    /// Authorize an address (typically another contract) to grant badges (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `granter` - The address to authorize
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful
+    pub fn authorize_granter(env: Env, granter: Address) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Granter(granter), &true);
+
+        Ok(())
+    }
+
+    /// Revoke a previously authorized granter (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `granter` - The address to revoke
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful
+    pub fn revoke_granter(env: Env, granter: Address) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Granter(granter));
+
+        Ok(())
+    }
+
+    /// Check whether an address is an authorized granter
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `granter` - The address to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the address may grant badges
+    pub fn is_granter(env: Env, granter: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Granter(granter))
+    }
+
+    /// Award a badge to a user on behalf of an authorized granter
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `granter` - The authorized granter awarding the badge
+    /// * `user` - The address of the user to award the badge to
+    /// * `badge` - The type of badge to award
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful, Error if not a granter or badge already awarded
+    pub fn grant_badge(
+        env: Env,
+        granter: Address,
+        user: Address,
+        badge: BadgeType,
+    ) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        granter.require_auth();
+        Self::require_granter(&env, &granter)?;
+
+        Self::award_badge_internal(&env, &user, badge, None)
+    }
+
+    /// Apply a batch of score deltas on behalf of an authorized granter
+    ///
+    /// Updates for unregistered users are skipped rather than failing the
+    /// batch, so a calling contract never has its own transaction reverted.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `granter` - The authorized granter applying the updates
+    /// * `updates` - Pairs of (user, delta) to apply
+    ///
+    /// # Returns
+    /// * `Result<u32, Error>` - Number of updates applied
+    pub fn grant_score_updates(
+        env: Env,
+        granter: Address,
+        updates: Vec<(Address, i128)>,
+    ) -> Result<u32, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        granter.require_auth();
+        Self::require_granter(&env, &granter)?;
+
+        if updates.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvInput);
+        }
+
+        let mut applied = 0;
+        for (user, delta) in updates.iter() {
+            if Self::apply_score_delta(&env, user, delta).is_ok() {
+                applied += 1;
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Set the badges an operator (typically another contract) may award (admin only)
+    ///
+    /// Replaces any previous list; an empty list revokes the operator.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `operator` - The address to authorize
+    /// * `badges` - The badge types the operator may award
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful
+    pub fn set_operator_badges(
+        env: Env,
+        operator: Address,
+        badges: Vec<BadgeType>,
+    ) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        let key = DataKey::OperatorBadges(operator);
+        if badges.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &badges);
+        }
+
+        Ok(())
+    }
+
+    /// Get the badges an operator may award
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `operator` - The address to check
+    ///
+    /// # Returns
+    /// * `Vec<BadgeType>` - The permitted badges, empty if not an operator
+    pub fn get_operator_badges(env: Env, operator: Address) -> Vec<BadgeType> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OperatorBadges(operator))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Award a badge on behalf of an operator permitted to award it
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `operator` - The operator awarding the badge
+    /// * `user` - The address of the user to award the badge to
+    /// * `badge` - The type of badge to award
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful, Unauthorized if the operator may not
+    ///   award this badge, or an error if the badge is already held
+    pub fn award_badge_from_operator(
+        env: Env,
+        operator: Address,
+        user: Address,
+        badge: BadgeType,
+    ) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        operator.require_auth();
+        if !Self::get_operator_badges(env.clone(), operator).contains(badge) {
+            return Err(Error::Unauthorized);
+        }
+
+        Self::award_badge_internal(&env, &user, badge, None)
+    }
+
+    /// Award a time-limited badge to a user (admin only)
+    ///
+    /// The badge is treated as not held once the ledger timestamp reaches
+    /// `expires_at`, and is pruned from the profile on its next write.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user to award the badge to
+    /// * `badge` - The type of badge to award
+    /// * `expires_at` - Ledger timestamp at which the badge expires
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful, Error if badge already held or expiry in the past
+    pub fn award_badge_with_expiry(
+        env: Env,
+        user: Address,
+        badge: BadgeType,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvInput);
+        }
+
+        Self::award_badge_internal(&env, &user, badge, Some(expires_at))
+    }
+
+    /// Check whether a user currently holds a badge
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    /// * `badge` - The type of badge to check
+    ///
+    /// # Returns
+    /// * `Result<bool, Error>` - True if the badge is held and not expired
+    pub fn has_badge(env: Env, user: Address, badge: BadgeType) -> Result<bool, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Profile(user.clone()))
+        {
+            return Err(Error::UserNotReg);
+        }
+        Ok(Self::holds_badge(&env, &user, badge) && !Self::is_badge_expired(&env, &user, badge))
+    }
+
+    /// Get a user's reputation profile
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `Result<ReputationProfile, Error>` - The user's profile or an error
+    pub fn get_profile(env: Env, user: Address) -> Result<ReputationProfile, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
         let mut profile = Self::get_profile_internal(&env, &user)?;
 
+        // Hide expired badges without writing; they are pruned on the next write
+        let mut badges = Vec::new(&env);
+        for badge in profile.badges.iter() {
+            if !Self::is_badge_expired(&env, &user, badge) {
+                badges.push_back(badge);
+            }
+        }
+        profile.badges = badges;
+
+        Ok(profile)
+    }
+
+    /// Get the highest-scoring registered users
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `limit` - Maximum number of users to return (capped at MAX_BATCH_SIZE)
+    ///
+    /// # Returns
+    /// * `Result<Vec<(Address, i128)>, Error>` - Users and scores, highest score first.
+    ///   Users with equal scores keep their registration order.
+    pub fn top_users(env: Env, limit: u32) -> Result<Vec<(Address, i128)>, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        let limit = limit.min(MAX_BATCH_SIZE);
+        let mut top: Vec<(Address, i128)> = Vec::new(&env);
+        if limit == 0 {
+            return Ok(top);
+        }
+
+        for user in Self::get_all_users(&env).iter() {
+            let profile = match Self::get_profile_internal(&env, &user) {
+                Ok(profile) => profile,
+                Err(_) => continue,
+            };
+
+            // Find the first entry with a strictly lower score
+            let mut index = top.len();
+            for i in 0..top.len() {
+                if top.get(i).unwrap().1 < profile.score {
+                    index = i;
+                    break;
+                }
+            }
+
+            if index < limit {
+                top.insert(index, (user, profile.score));
+                if top.len() > limit {
+                    top.pop_back();
+                }
+            }
+        }
+
+        Ok(top)
+    }
+
+    /// Set the score below which users are considered banned (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `threshold` - Users with a score strictly below this value are banned
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if successful
+    pub fn set_ban_threshold(env: Env, threshold: i128) -> Result<(), Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BanThreshold, &threshold);
+
+        Ok(())
+    }
+
+    /// Get the ban threshold, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<i128>` - The threshold, or None when banning is disabled
+    pub fn get_ban_threshold(env: Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::BanThreshold)
+    }
+
+    /// Check whether a user's score is below the ban threshold
+    ///
+    /// Unregistered users and deployments without a threshold are never banned.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `bool` - True if the user is banned
+    pub fn is_banned(env: Env, user: Address) -> bool {
+        let threshold: i128 = match env.storage().instance().get(&DataKey::BanThreshold) {
+            Some(threshold) => threshold,
+            None => return false,
+        };
+        match Self::get_profile_internal(&env, &user) {
+            Ok(profile) => profile.score < threshold,
+            Err(_) => false,
+        }
+    }
+
+    /// Fail if a user is banned; intended for other contracts to call before gated actions
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if the user is not banned, Unauthorized otherwise
+    pub fn require_not_banned(env: Env, user: Address) -> Result<(), Error> {
+        if Self::is_banned(env, user) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Apply the decay owed since the user's last decay
+    ///
+    /// Each elapsed REPUTATION_DECAY_PERIOD removes REPUTATION_DECAY_PER_PERIOD,
+    /// scaled by the user's momentum: recent positive updates slow decay, while
+    /// inactivity drives momentum negative and accelerates it. At most
+    /// MAX_BATCH_SIZE periods are processed per call. Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `Result<i128, Error>` - The score after decay
+    pub fn apply_decay(env: Env, user: Address) -> Result<i128, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        let mut profile = Self::get_profile_internal(&env, &user)?;
+        let now = env.ledger().timestamp();
+        let decay_key = DataKey::LastDecay(user.clone());
+        let last_decay: u64 = env.storage().persistent().get(&decay_key).unwrap_or(now);
+
+        let periods = ((now - last_decay) / REPUTATION_DECAY_PERIOD).min(MAX_BATCH_SIZE as u64);
+        if periods == 0 {
+            // Starts the clock for users registered before decay existed
+            env.storage().persistent().set(&decay_key, &last_decay);
+            return Ok(profile.score);
+        }
+
+        let mut momentum = Self::get_momentum(env.clone(), user.clone());
+        let mut score = profile.score;
+        let min = Self::score_bounds(&env).min;
+        for _ in 0..periods {
+            score = (score - decay_for_momentum(momentum)).max(min);
+            momentum = (momentum - MOMENTUM_DECAY_STEP).max(-MAX_MOMENTUM);
+        }
+
+        env.storage().persistent().set(
+            &decay_key,
+            &(last_decay + periods * REPUTATION_DECAY_PERIOD),
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::Momentum(user.clone()), &momentum);
+
+        if score != profile.score {
+            profile.score = score;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Profile(user.clone()), &profile);
+            env.events().publish((REPUTATION_UPDATED,), (user, score));
+        }
+
+        Ok(score)
+    }
+
+    /// Get a user's current momentum
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `i128` - Momentum in `-MAX_MOMENTUM..=MAX_MOMENTUM`, 0 if never updated
+    pub fn get_momentum(env: Env, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Momentum(user))
+            .unwrap_or(0)
+    }
+
+    /// Freeze current scores under a snapshot id (admin only)
+    ///
+    /// Processes at most MAX_BATCH_SIZE users per call. Call again with the same
+    /// `snapshot_id` to resume until it returns true. A completed snapshot is
+    /// never overwritten.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `snapshot_id` - Identifier of the snapshot
+    ///
+    /// # Returns
+    /// * `Result<bool, Error>` - True once every registered user has been recorded
+    pub fn snapshot_scores(env: Env, snapshot_id: u64) -> Result<bool, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        // Get admin and require authorization
+        let admin = Self::get_admin(&env)?;
+        admin.require_auth();
+
+        let cursor_key = DataKey::SnapshotCursor(snapshot_id);
+        let cursor: u32 = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+        if cursor == u32::MAX {
+            return Ok(true);
+        }
+
+        let all_users = Self::get_all_users(&env);
+        let end = all_users.len().min(cursor.saturating_add(MAX_BATCH_SIZE));
+        for i in cursor..end {
+            let user = all_users.get(i).unwrap();
+            let profile = Self::get_profile_internal(&env, &user)?;
+            env.storage()
+                .persistent()
+                .set(&DataKey::SnapshotScore(snapshot_id, user), &profile.score);
+        }
+
+        let complete = end == all_users.len();
+        let next_cursor = if complete { u32::MAX } else { end };
+        env.storage().persistent().set(&cursor_key, &next_cursor);
+
+        Ok(complete)
+    }
+
+    /// Get a user's score as recorded in a snapshot
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `snapshot_id` - Identifier of the snapshot
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    /// * `Result<i128, Error>` - The frozen score, or NotFound if the user was not recorded
+    pub fn get_snapshot_score(env: Env, snapshot_id: u64, user: Address) -> Result<i128, Error> {
+        // Check initialization
+        Self::require_initialized(&env)?;
+
+        env.storage()
+            .persistent()
+            .get(&DataKey::SnapshotScore(snapshot_id, user))
+            .ok_or(Error::NotFound)
+    }
+
+    /// Get the admin address
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Result<Address, Error>` - The admin address or an error
+    pub fn get_admin(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInit)
+    }
+
+    /// Check that an address is an authorized granter
+    fn require_granter(env: &Env, granter: &Address) -> Result<(), Error> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Granter(granter.clone()))
+        {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Internal helper to get the score scale, falling back to the shared constants
+    fn score_bounds(env: &Env) -> ScoreBounds {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoreBounds)
+            .unwrap_or(ScoreBounds {
+                min: REPUTATION_MIN,
+                max: REPUTATION_MAX,
+                start: REPUTATION_START,
+            })
+    }
+
+    /// Check if the contract is initialized
+    fn require_initialized(env: &Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(Error::NotInit);
+        }
+        Ok(())
+    }
+
+    /// Internal helper to get the registered user index
+    fn get_all_users(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllUsers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Internal helper to apply a clamped score change and fold it into momentum
+    fn apply_score_delta(env: &Env, user: Address, delta: i128) -> Result<i128, Error> {
+        // Get existing profile
+        let mut profile = Self::get_profile_internal(env, &user)?;
+        Self::prune_expired_badges(env, &mut profile);
+
         // Calculate new score with clamping
+        let bounds = Self::score_bounds(env);
         let new_score = profile.score.saturating_add(delta);
-        let clamped_score = new_score.clamp(REPUTATION_MIN, REPUTATION_MAX);
+        let clamped_score = new_score.clamp(bounds.min, bounds.max);
 
         // Update profile
         profile.score = clamped_score;
@@ -153,6 +891,20 @@ impl ReputationContract {
             .persistent()
             .set(&DataKey::Profile(user.clone()), &profile);
 
+        // Track momentum from recent changes
+        let momentum = Self::get_momentum(env.clone(), user.clone())
+            .saturating_add(delta)
+            .clamp(-MAX_MOMENTUM, MAX_MOMENTUM);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Momentum(user.clone()), &momentum);
+
+        // Surface updates that were truncated by the score bounds
+        if clamped_score != new_score {
+            env.events()
+                .publish((SCORE_CLAMPED,), (user.clone(), delta, clamped_score));
+        }
+
         // Emit score update event
         env.events()
             .publish((REPUTATION_UPDATED,), (user, clamped_score));
@@ -160,33 +912,40 @@ impl ReputationContract {
         Ok(clamped_score)
     }
 
-    /// Award a badge to a user (admin only)
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `user` - The address of the user to award the badge to
-    /// * `badge` - The type of badge to award
-    ///
-    /// # Returns
-    /// * `Result<(), Error>` - Ok if successful, Error if badge already awarded
-    pub fn award_badge(env: Env, user: Address, badge: BadgeType) -> Result<(), Error> {
-        // Check initialization
-        Self::require_initialized(&env)?;
-
-        // Get admin and require authorization
-        let admin = Self::get_admin(&env)?;
-        admin.require_auth();
+    /// Internal helper to get a profile
+    fn get_profile_internal(env: &Env, user: &Address) -> Result<ReputationProfile, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Profile(user.clone()))
+            .ok_or(Error::UserNotReg)
+    }
 
+    /// Shared badge award path, with an optional expiry
+    fn award_badge_internal(
+        env: &Env,
+        user: &Address,
+        badge: BadgeType,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
         // Get existing profile
-        let mut profile = Self::get_profile_internal(&env, &user)?;
+        let mut profile = Self::get_profile_internal(env, user)?;
+        Self::prune_expired_badges(env, &mut profile);
 
         // Check for duplicate badge
-        if Self::has_badge(&profile.badges, badge) {
+        if Self::holds_badge(env, user, badge) {
             return Err(Error::BadgeAw);
         }
 
-        // Award badge
+        // Award badge, keeping the ordered list for enumeration
         profile.badges.push_back(badge);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BadgeHeld(user.clone(), badge), &true);
+        if let Some(expires_at) = expires_at {
+            env.storage()
+                .persistent()
+                .set(&DataKey::BadgeExpiry(user.clone(), badge), &expires_at);
+        }
 
         // Store updated profile
         env.storage()
@@ -194,71 +953,84 @@ impl ReputationContract {
             .set(&DataKey::Profile(user.clone()), &profile);
 
         // Emit badge award event
-        env.events().publish((BADGE_EARNED,), (user, badge as u32));
+        env.events()
+            .publish((BADGE_EARNED,), (user.clone(), badge as u32));
 
         Ok(())
     }
 
-    /// Get a user's reputation profile
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `user` - The address of the user
-    ///
-    /// # Returns
-    /// * `Result<ReputationProfile, Error>` - The user's profile or an error
-    pub fn get_profile(env: Env, user: Address) -> Result<ReputationProfile, Error> {
-        // Check initialization
-        Self::require_initialized(&env)?;
-
-        Self::get_profile_internal(&env, &user)
+    /// Check whether a time-limited badge has reached its expiry
+    fn is_badge_expired(env: &Env, user: &Address, badge: BadgeType) -> bool {
+        match env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::BadgeExpiry(user.clone(), badge))
+        {
+            Some(expires_at) => env.ledger().timestamp() >= expires_at,
+            None => false,
+        }
     }
 
-    /// Get the admin address
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
+    /// Remove expired badges (and their expiry entries) from a profile before it is written
     ///
-    /// # Returns
-    /// * `Result<Address, Error>` - The admin address or an error
-    pub fn get_admin(env: &Env) -> Result<Address, Error> {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInit)
-    }
-
-    /// Check if the contract is initialized
-    fn require_initialized(env: &Env) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Initialized) {
-            return Err(Error::NotInit);
+    /// Also backfills the `BadgeHeld` markers of the badges that remain.
+    fn prune_expired_badges(env: &Env, profile: &mut ReputationProfile) {
+        let mut badges = Vec::new(env);
+        for badge in profile.badges.iter() {
+            if Self::is_badge_expired(env, &profile.user, badge) {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::BadgeExpiry(profile.user.clone(), badge));
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::BadgeHeld(profile.user.clone(), badge));
+            } else {
+                badges.push_back(badge);
+            }
         }
-        Ok(())
+        profile.badges = badges;
+        Self::backfill_badge_markers(env, profile);
     }
 
-    /// Internal helper to get a profile
-    fn get_profile_internal(env: &Env, user: &Address) -> Result<ReputationProfile, Error> {
+    /// Check whether a badge is in a user's profile, expired or not, without scanning it
+    fn holds_badge(env: &Env, user: &Address, badge: BadgeType) -> bool {
         env.storage()
             .persistent()
-            .get(&DataKey::Profile(user.clone()))
-            .ok_or(Error::UserNotReg)
+            .has(&DataKey::BadgeHeld(user.clone(), badge))
     }
 
-    /// Check if a badge list contains a specific badge
-    fn has_badge(badges: &Vec<BadgeType>, badge: BadgeType) -> bool {
-        for i in 0..badges.len() {
-            if badges.get(i).unwrap() == badge {
-                return true;
+    /// Write any `BadgeHeld` marker missing for a badge in the profile's list
+    ///
+    /// Profiles written before the markers existed only list their badges.
+    ///
+    /// # Returns
+    /// * `u32` - Number of markers written
+    fn backfill_badge_markers(env: &Env, profile: &ReputationProfile) -> u32 {
+        let mut written = 0;
+        for badge in profile.badges.iter() {
+            let key = DataKey::BadgeHeld(profile.user.clone(), badge);
+            if !env.storage().persistent().has(&key) {
+                env.storage().persistent().set(&key, &true);
+                written += 1;
             }
         }
-        false
+        written
     }
 }
 
+/// Score lost in one decay period at the given momentum
+///
+/// Ranges from half the base decay at `MAX_MOMENTUM` to one and a half times
+/// the base decay at `-MAX_MOMENTUM`.
+fn decay_for_momentum(momentum: i128) -> i128 {
+    REPUTATION_DECAY_PER_PERIOD * (2 * MAX_MOMENTUM - momentum) / (2 * MAX_MOMENTUM)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::{FromVal, IntoVal, Val};
 
     fn setup_env() -> (Env, Address, ReputationContractClient<'static>, Address) {
         let env = Env::default();
@@ -385,6 +1157,74 @@ mod tests {
         assert_eq!(new_score, REPUTATION_MAX);
     }
 
+    #[test]
+    fn test_custom_bounds_used_for_start_and_clamping() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize_with_bounds(&admin, &-50, &500, &0);
+        assert_eq!(
+            client.get_score_bounds(),
+            ScoreBounds {
+                min: -50,
+                max: 500,
+                start: 0
+            }
+        );
+
+        assert_eq!(client.register_user(&user).score, 0);
+        assert_eq!(client.update_score(&user, &1000), 500);
+        assert_eq!(client.update_score(&user, &-1000), -50);
+
+        let mut updates = Vec::new(&env);
+        updates.push_back((user.clone(), 10_000));
+        client.batch_update_scores(&updates, &true);
+        assert_eq!(client.get_profile(&user).score, 500);
+    }
+
+    #[test]
+    fn test_custom_bounds_must_contain_start() {
+        let (_, admin, client, _) = setup_env();
+        assert_eq!(
+            client.try_initialize_with_bounds(&admin, &0, &100, &100),
+            Err(Ok(Error::InvInput))
+        );
+        assert_eq!(
+            client.try_initialize_with_bounds(&admin, &100, &0, &50),
+            Err(Ok(Error::InvInput))
+        );
+
+        // The simple initializer keeps the shared defaults
+        client.initialize(&admin);
+        assert_eq!(client.get_score_bounds().max, REPUTATION_MAX);
+    }
+
+    #[test]
+    fn test_score_clamped_event_only_on_truncation() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let clamp_events = |env: &Env| {
+            let topics: Vec<Val> = (SCORE_CLAMPED,).into_val(env);
+            let mut found = Vec::new(env);
+            for (_, event_topics, data) in env.events().all().iter() {
+                if event_topics == topics {
+                    found.push_back(<(Address, i128, i128)>::from_val(env, &data));
+                }
+            }
+            found
+        };
+
+        client.update_score(&user, &10);
+        assert!(clamp_events(&env).is_empty());
+
+        client.update_score(&user, &REPUTATION_MAX);
+        let events = clamp_events(&env);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events.get(0).unwrap(),
+            (user, REPUTATION_MAX, REPUTATION_MAX)
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #46)")]
     fn test_update_score_user_not_registered() {
@@ -449,8 +1289,269 @@ mod tests {
         client.award_badge(&user, &BadgeType::Contributor);
     }
 
+    #[test]
+    fn test_legacy_badges_without_presence_keys() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        let other = Address::generate(&env);
+        client.register_user(&user);
+        client.register_user(&other);
+
+        // Seed profiles written before BadgeHeld existed: listed badge, no marker
+        let seed_legacy_badge = |user: &Address| {
+            env.as_contract(&client.address, || {
+                let mut profile: ReputationProfile = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Profile(user.clone()))
+                    .unwrap();
+                profile.badges.push_back(BadgeType::Contributor);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Profile(user.clone()), &profile);
+            })
+        };
+        seed_legacy_badge(&user);
+        seed_legacy_badge(&other);
+        let has_marker = |user: &Address| {
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .has(&DataKey::BadgeHeld(user.clone(), BadgeType::Contributor))
+            })
+        };
+
+        // Reads only consult the marker and never write it
+        assert!(!client.has_badge(&user, &BadgeType::Contributor));
+        assert!(!has_marker(&user));
+
+        // The award path backfills before its duplicate check
+        assert_eq!(
+            client.try_award_badge(&user, &BadgeType::Contributor),
+            Err(Ok(Error::BadgeAw))
+        );
+        assert_eq!(client.get_profile(&user).badges.len(), 1);
+
+        // So does any other profile write
+        client.update_score(&other, &1);
+        assert!(has_marker(&other));
+        assert!(client.has_badge(&other, &BadgeType::Contributor));
+
+        // Untouched profiles are migrated explicitly, once
+        let users = Vec::from_array(&env, [user.clone(), Address::generate(&env)]);
+        assert_eq!(client.migrate_badge_markers(&users), 1);
+        assert!(has_marker(&user));
+        assert!(client.has_badge(&user, &BadgeType::Contributor));
+        assert!(!client.has_badge(&user, &BadgeType::TopInvestor));
+        assert_eq!(client.migrate_badge_markers(&users), 0);
+    }
+
+    #[test]
+    fn test_badge_membership_uses_presence_keys() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        client.award_badge(&user, &BadgeType::Contributor);
+        client.award_badge(&user, &BadgeType::VerifiedCreator);
+        assert!(client.has_badge(&user, &BadgeType::Contributor));
+        assert!(client.has_badge(&user, &BadgeType::VerifiedCreator));
+        assert!(!client.has_badge(&user, &BadgeType::TopInvestor));
+        assert_eq!(
+            client.try_award_badge(&user, &BadgeType::Contributor),
+            Err(Ok(Error::BadgeAw))
+        );
+
+        // Each held badge has its own entry; the ordered list is kept for enumeration
+        let held = |badge: BadgeType| {
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .has(&DataKey::BadgeHeld(user.clone(), badge))
+            })
+        };
+        assert!(held(BadgeType::Contributor));
+        assert!(held(BadgeType::VerifiedCreator));
+        assert!(!held(BadgeType::TopInvestor));
+        let profile = client.get_profile(&user);
+        assert_eq!(profile.badges.get(0).unwrap(), BadgeType::Contributor);
+        assert_eq!(profile.badges.get(1).unwrap(), BadgeType::VerifiedCreator);
+
+        assert_eq!(
+            client.try_has_badge(&Address::generate(&env), &BadgeType::Contributor),
+            Err(Ok(Error::UserNotReg))
+        );
+    }
+
+    #[test]
+    fn test_badge_with_expiry_held_before_expiry() {
+        let (env, admin, client, user) = setup_env();
+        env.ledger().set_timestamp(1000);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        client.award_badge_with_expiry(&user, &BadgeType::TopInvestor, &2000);
+
+        env.ledger().set_timestamp(1999);
+        assert!(client.has_badge(&user, &BadgeType::TopInvestor));
+        assert_eq!(client.get_profile(&user).badges.len(), 1);
+    }
+
+    #[test]
+    fn test_badge_with_expiry_not_held_after_expiry() {
+        let (env, admin, client, user) = setup_env();
+        env.ledger().set_timestamp(1000);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        client.award_badge(&user, &BadgeType::Contributor);
+        client.award_badge_with_expiry(&user, &BadgeType::TopInvestor, &2000);
+
+        env.ledger().set_timestamp(2000);
+        assert!(!client.has_badge(&user, &BadgeType::TopInvestor));
+        assert!(client.has_badge(&user, &BadgeType::Contributor));
+
+        let profile = client.get_profile(&user);
+        assert_eq!(profile.badges.len(), 1);
+        assert_eq!(profile.badges.get(0).unwrap(), BadgeType::Contributor);
+    }
+
+    #[test]
+    fn test_expired_badge_pruned_on_next_write() {
+        let (env, admin, client, user) = setup_env();
+        env.ledger().set_timestamp(1000);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        client.award_badge_with_expiry(&user, &BadgeType::TopInvestor, &2000);
+        env.ledger().set_timestamp(3000);
+
+        // Reads hide the badge but leave storage untouched
+        let stored = |env: &Env| -> ReputationProfile {
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::Profile(user.clone()))
+                    .unwrap()
+            })
+        };
+        assert_eq!(stored(&env).badges.len(), 1);
+
+        // Any profile write prunes the expired badge and its expiry entry
+        client.update_score(&user, &10);
+        assert_eq!(stored(&env).badges.len(), 0);
+        let has_expiry = env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .has(&DataKey::BadgeExpiry(user.clone(), BadgeType::TopInvestor))
+        });
+        assert!(!has_expiry);
+        let held = env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .has(&DataKey::BadgeHeld(user.clone(), BadgeType::TopInvestor))
+        });
+        assert!(!held);
+
+        // The badge can be earned again once it has lapsed
+        client.award_badge_with_expiry(&user, &BadgeType::TopInvestor, &5000);
+        assert!(client.has_badge(&user, &BadgeType::TopInvestor));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_award_badge_with_past_expiry_rejected() {
+        let (env, admin, client, user) = setup_env();
+        env.ledger().set_timestamp(1000);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        client.award_badge_with_expiry(&user, &BadgeType::TopInvestor, &1000);
+    }
+
+    #[test]
+    fn test_authorized_granter_can_grant_badge() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        let granter = Address::generate(&env);
+        client.authorize_granter(&granter);
+        assert!(client.is_granter(&granter));
+
+        client.grant_badge(&granter, &user, &BadgeType::MilestoneAchiever);
+        assert!(client.has_badge(&user, &BadgeType::MilestoneAchiever));
+
+        // Duplicate grants are rejected like admin awards
+        let result = client.try_grant_badge(&granter, &user, &BadgeType::MilestoneAchiever);
+        assert_eq!(result, Err(Ok(Error::BadgeAw)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #3)")]
+    fn test_unauthorized_granter_rejected() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        let granter = Address::generate(&env);
+        client.authorize_granter(&granter);
+        client.revoke_granter(&granter);
+        assert!(!client.is_granter(&granter));
+
+        client.grant_badge(&granter, &user, &BadgeType::MilestoneAchiever);
+    }
+
+    #[test]
+    fn test_granter_applies_score_updates() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let unregistered = Address::generate(&env);
+
+        let granter = Address::generate(&env);
+        let mut updates = Vec::new(&env);
+        updates.push_back((user.clone(), 15));
+        updates.push_back((unregistered, 5));
+
+        let result = client.try_grant_score_updates(&granter, &updates);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        client.authorize_granter(&granter);
+        assert_eq!(client.grant_score_updates(&granter, &updates), 1);
+        assert_eq!(client.get_profile(&user).score, REPUTATION_START + 15);
+    }
+
     // ==================== Get Profile Tests ====================
 
+    #[test]
+    fn test_operator_awards_only_permitted_badges() {
+        let (env, admin, client, user) = setup_env();
+        let operator = Address::generate(&env);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        let mut permitted = Vec::new(&env);
+        permitted.push_back(BadgeType::FirstProject);
+        permitted.push_back(BadgeType::Contributor);
+        client.set_operator_badges(&operator, &permitted);
+        assert_eq!(client.get_operator_badges(&operator), permitted);
+
+        client.award_badge_from_operator(&operator, &user, &BadgeType::FirstProject);
+        assert!(client.has_badge(&user, &BadgeType::FirstProject));
+
+        let result =
+            client.try_award_badge_from_operator(&operator, &user, &BadgeType::VerifiedCreator);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        assert!(!client.has_badge(&user, &BadgeType::VerifiedCreator));
+
+        // Clearing the list revokes the operator
+        client.set_operator_badges(&operator, &Vec::new(&env));
+        let result =
+            client.try_award_badge_from_operator(&operator, &user, &BadgeType::Contributor);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
     #[test]
     fn test_get_profile_success() {
         let (_, admin, client, user) = setup_env();
@@ -483,6 +1584,220 @@ mod tests {
         client.get_profile(&user);
     }
 
+    // ==================== Leaderboard Tests ====================
+
+    #[test]
+    fn test_top_users_ordering() {
+        let (env, admin, client, _) = setup_env();
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        let dave = Address::generate(&env);
+        for user in [&alice, &bob, &carol, &dave] {
+            client.register_user(user);
+        }
+
+        client.update_score(&alice, &50);
+        client.update_score(&bob, &300);
+        client.update_score(&carol, &-20);
+
+        let top = client.top_users(&3);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.get(0).unwrap(), (bob.clone(), REPUTATION_START + 300));
+        assert_eq!(top.get(1).unwrap(), (alice.clone(), REPUTATION_START + 50));
+        assert_eq!(top.get(2).unwrap(), (dave.clone(), REPUTATION_START));
+
+        // A later score change reorders the leaderboard
+        client.update_score(&carol, &1000);
+        let top = client.top_users(&10);
+        assert_eq!(top.len(), 4);
+        assert_eq!(top.get(0).unwrap().0, carol);
+        assert_eq!(top.get(3).unwrap().0, dave);
+    }
+
+    #[test]
+    fn test_top_users_empty_and_zero_limit() {
+        let (_, admin, client, user) = setup_env();
+        client.initialize(&admin);
+
+        assert_eq!(client.top_users(&5).len(), 0);
+
+        client.register_user(&user);
+        assert_eq!(client.top_users(&0).len(), 0);
+        assert_eq!(client.top_users(&5).len(), 1);
+    }
+
+    // ==================== Batch Tests ====================
+
+    #[test]
+    fn test_atomic_batch_reverts_on_invalid_item() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let unregistered = Address::generate(&env);
+
+        let mut updates = Vec::new(&env);
+        updates.push_back((user.clone(), 10));
+        updates.push_back((unregistered, 10));
+
+        let result = client.try_batch_update_scores(&updates, &true);
+        assert_eq!(result, Err(Ok(Error::UserNotReg)));
+        assert_eq!(client.get_profile(&user).score, REPUTATION_START);
+    }
+
+    #[test]
+    fn test_non_atomic_batch_skips_invalid_item() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let unregistered = Address::generate(&env);
+
+        let mut updates = Vec::new(&env);
+        updates.push_back((user.clone(), 10));
+        updates.push_back((unregistered, 10));
+
+        assert_eq!(client.batch_update_scores(&updates, &false), 1);
+        assert_eq!(client.get_profile(&user).score, REPUTATION_START + 10);
+    }
+
+    // ==================== Ban Threshold Tests ====================
+
+    #[test]
+    fn test_is_banned_below_threshold() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let good = Address::generate(&env);
+        client.register_user(&good);
+
+        // No threshold configured: nobody is banned
+        client.update_score(&user, &-80);
+        assert!(!client.is_banned(&user));
+
+        client.set_ban_threshold(&50);
+        assert_eq!(client.get_ban_threshold(), Some(50));
+        assert!(client.is_banned(&user));
+        assert!(!client.is_banned(&good));
+        assert!(client.try_require_not_banned(&user).is_err());
+        client.require_not_banned(&good);
+
+        // Climbing back to the threshold lifts the ban
+        client.update_score(&user, &30);
+        assert!(!client.is_banned(&user));
+    }
+
+    #[test]
+    fn test_unregistered_user_not_banned() {
+        let (_, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.set_ban_threshold(&REPUTATION_MAX);
+
+        assert!(!client.is_banned(&user));
+    }
+
+    // ==================== Decay Tests ====================
+
+    #[test]
+    fn test_recent_positive_activity_slows_decay() {
+        let (env, admin, client, active) = setup_env();
+        env.ledger().set_timestamp(1000);
+        let inactive = Address::generate(&env);
+        client.initialize(&admin);
+        client.register_user(&active);
+        client.register_user(&inactive);
+
+        client.update_score(&active, &50);
+        assert_eq!(client.get_momentum(&active), 50);
+        assert_eq!(client.get_momentum(&inactive), 0);
+
+        env.ledger()
+            .set_timestamp(1000 + 2 * REPUTATION_DECAY_PERIOD);
+        let active_loss = 150 - client.apply_decay(&active);
+        let inactive_loss = REPUTATION_START - client.apply_decay(&inactive);
+
+        // 7 + 8 with momentum 50 -> 25, versus 10 + 11 with momentum 0 -> -25
+        assert_eq!(active_loss, 15);
+        assert_eq!(inactive_loss, 21);
+        assert!(active_loss < inactive_loss);
+    }
+
+    #[test]
+    fn test_decay_is_idempotent_within_period() {
+        let (env, admin, client, user) = setup_env();
+        env.ledger().set_timestamp(1000);
+        client.initialize(&admin);
+        client.register_user(&user);
+
+        env.ledger()
+            .set_timestamp(1000 + REPUTATION_DECAY_PERIOD + 10);
+        let decayed = client.apply_decay(&user);
+        assert_eq!(decayed, REPUTATION_START - REPUTATION_DECAY_PER_PERIOD);
+        assert_eq!(client.apply_decay(&user), decayed);
+        assert_eq!(client.get_momentum(&user), -MOMENTUM_DECAY_STEP);
+    }
+
+    // ==================== Snapshot Tests ====================
+
+    #[test]
+    fn test_snapshot_unchanged_by_live_updates() {
+        let (env, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.register_user(&user);
+        let other = Address::generate(&env);
+        client.register_user(&other);
+        client.update_score(&user, &50);
+
+        assert!(client.snapshot_scores(&1));
+
+        client.update_score(&user, &500);
+        client.update_score(&other, &-40);
+
+        assert_eq!(client.get_snapshot_score(&1, &user), REPUTATION_START + 50);
+        assert_eq!(client.get_snapshot_score(&1, &other), REPUTATION_START);
+
+        // Re-running a completed snapshot does not overwrite it
+        assert!(client.snapshot_scores(&1));
+        assert_eq!(client.get_snapshot_score(&1, &user), REPUTATION_START + 50);
+
+        // A new snapshot captures the live scores
+        client.snapshot_scores(&2);
+        assert_eq!(client.get_snapshot_score(&2, &user), REPUTATION_START + 550);
+    }
+
+    #[test]
+    fn test_snapshot_resumes_across_calls() {
+        let (env, admin, client, _) = setup_env();
+        client.initialize(&admin);
+
+        let mut users = Vec::new(&env);
+        for _ in 0..MAX_BATCH_SIZE + 5 {
+            let user = Address::generate(&env);
+            client.register_user(&user);
+            users.push_back(user);
+        }
+        let last = users.get(MAX_BATCH_SIZE + 4).unwrap();
+
+        assert!(!client.snapshot_scores(&7));
+        assert!(client.try_get_snapshot_score(&7, &last).is_err());
+
+        assert!(client.snapshot_scores(&7));
+        for user in users.iter() {
+            assert_eq!(client.get_snapshot_score(&7, &user), REPUTATION_START);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_snapshot_score_missing_user() {
+        let (_, admin, client, user) = setup_env();
+        client.initialize(&admin);
+        client.snapshot_scores(&1);
+
+        client.get_snapshot_score(&1, &user);
+    }
+
     // ==================== Access Control Tests ====================
 
     #[test]
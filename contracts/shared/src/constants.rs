@@ -28,11 +28,38 @@ pub const MILESTONE_APPROVAL_THRESHOLD: u32 = 6000;
 /// Minimum validators required
 pub const MIN_VALIDATORS: u32 = 3;
 
+/// Default maximum number of milestones a single project may create; the escrow admin can override it
+pub const MAX_MILESTONES_PER_PROJECT: u64 = 50;
+
+/// Default smallest amount a milestone may carry; the escrow admin can override it
+pub const MIN_MILESTONE_AMOUNT: i128 = 10;
+
+/// Maximum number of times a rejected milestone may be resubmitted
+pub const MAX_RESUBMISSIONS: u32 = 3;
+
+/// Maximum length in bytes of a milestone proof URI
+pub const MAX_URI_LEN: u32 = 256;
+
 /// Reputation score ranges
 pub const REPUTATION_MIN: i128 = 0;
 pub const REPUTATION_MAX: i128 = 10000;
 pub const REPUTATION_START: i128 = 100;
 
+/// Reputation decay interval (1 week) and base score lost per interval
+pub const REPUTATION_DECAY_PERIOD: u64 = 604800;
+pub const REPUTATION_DECAY_PER_PERIOD: i128 = 10;
+
+/// Momentum bounds; full positive momentum halves decay, full negative momentum adds half again
+pub const MAX_MOMENTUM: i128 = 100;
+/// Momentum lost for every decay period that passes
+pub const MOMENTUM_DECAY_STEP: i128 = 25;
+
+/// Recommended minimum reputation for creating an escrow when the gate is enabled
+pub const MIN_CREATOR_REPUTATION: i128 = REPUTATION_START;
+
+/// Maximum number of items processed by a single batch or scan call
+pub const MAX_BATCH_SIZE: u32 = 50;
+
 /// Governance quorum (20%)
 pub const GOVERNANCE_QUORUM: u32 = 2000;
 
@@ -50,19 +77,49 @@ pub const JURY_SIZE: u32 = 7;
 /// Jury size for appeals (must be odd and larger than JURY_SIZE)
 pub const APPEAL_JURY_SIZE: u32 = 13;
 
+/// Penalty for a minority or missing vote, in basis points of the juror's stake (10%)
+pub const JUROR_SLASH_BPS: u32 = 1000;
+
+/// Share of slashed stake, in basis points, compensating the winning side of a dispute (50%)
+pub const SLASH_TO_WINNER_BPS: u32 = 5000;
+
+/// Reputation gained by a juror for an on-time majority vote
+pub const JUROR_SERVICE_SCORE: i128 = 5;
+
+/// Reputation lost by a juror slashed for a minority or missing vote
+pub const JUROR_SLASH_SCORE: i128 = 10;
+
+/// Largest contribution a juror may hold in the disputed project before being excluded as a backer
+pub const MAX_JUROR_CONTRIBUTION: i128 = 1_000_000_000; // 100 XLM
+
+/// Share of a panel, in basis points, that must reveal for its tally to resolve the dispute (a majority)
+pub const MIN_REVEAL_QUORUM: u32 = 5001;
+
+/// Bond locked from a juror's stake at commit time, per dispute (0 disables it)
+pub const PER_DISPUTE_BOND: i128 = 0;
+
 /// Voting commit period (e.g., 3 days in seconds)
 pub const VOTING_COMMIT_PERIOD: u64 = 259200;
 
 /// Voting reveal period (e.g., 2 days in seconds)
 pub const VOTING_REVEAL_PERIOD: u64 = 172800;
 
+/// Default grace after the reveal period during which late reveals are counted but not rewarded (1 hour)
+pub const REVEAL_GRACE_PERIOD: u64 = 3600;
+
+/// Time validators have to vote on a submitted milestone before it can be finalized (7 days)
+pub const MILESTONE_VOTE_PERIOD: u64 = 604800;
+
+/// Time after a milestone submission during which a dispute can be opened (7 days)
+pub const CONTEST_WINDOW_SECS: u64 = 604800;
+
 /// Appeal window period (e.g., 5 days in seconds)
 pub const APPEAL_WINDOW_PERIOD: u64 = 432000;
 
 /// Minimum time-lock before a jury dispute outcome can be enforced on-chain (seconds)
 pub const RESOLUTION_TIME_LOCK: u64 = 259200; // 72 hours
 
-/// Flat fee for filing an appeal
+/// Base fee for filing an appeal; the nth appeal on a dispute costs n times this
 pub const APPEAL_FEE: i128 = 1_000_000_000;
 
 /// Maximum number of appeals allowed
@@ -1,7 +1,7 @@
 use soroban_sdk::contracterror;
 
 #[contracterror]
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Error {
     NotInit = 1,
@@ -48,16 +48,18 @@ pub enum Error {
     InsufFunds = 34,
     InvDist = 35,
     NoClaim = 36,
-    DistFail = 37,
 
-    // Subscription errors
-    SubNotAct = 38,
-    InvSubPer = 39,
-    SubExists = 40,
-    WithdrLock = 41,
+    // Retired codes 37-42. A contract spec allows at most 50 error cases, so
+    // these unraised variants were dropped to make room for codes 51 and up.
+    // Their numbers stay reserved and must not be reused:
+    //   37 DistFail   - never raised
+    //   38 SubNotAct  - subscription-pool defines its own subscription errors
+    //   39 InvSubPer  - "
+    //   40 SubExists  - "
+    //   41 WithdrLock - "
+    //   42 RepErr     - never raised; RepTooLow (52) covers the reputation gate
 
     // Reputation errors
-    RepErr = 42,
     BadgeNotErn = 43,
     UserAlreadyReg = 44,
     BadgeAw = 45,
@@ -68,4 +70,11 @@ pub enum Error {
     InsufVote = 48,
     PropExc = 49,
     QuorumNR = 50,
+
+    // Escrow limits
+    MstoneLimit = 51,
+    RepTooLow = 52,
+    Reentrancy = 53,
+    DepositWindowClosed = 54,
+    InsufficientJurors = 55,
 }
@@ -20,7 +20,12 @@ pub const MILESTONE_SUBMITTED: Symbol = symbol_short!("m_submit");
 pub const MILESTONE_APPROVED: Symbol = symbol_short!("m_apprv");
 pub const MILESTONE_REJECTED: Symbol = symbol_short!("m_reject");
 pub const MILESTONE_COMPLETED: Symbol = symbol_short!("milestone");
+pub const MILESTONE_ABANDONED: Symbol = symbol_short!("m_abandon");
 pub const VALIDATORS_UPDATED: Symbol = symbol_short!("v_update");
+pub const VALIDATOR_ADDED: Symbol = symbol_short!("v_added");
+pub const VALIDATOR_REMOVED: Symbol = symbol_short!("v_removed");
+pub const SURPLUS_WITHDRAWN: Symbol = symbol_short!("surplus");
+pub const OPERATOR_SET: Symbol = symbol_short!("op_set");
 
 // Dispute events
 pub const JURY_SELECTED: Symbol = symbol_short!("j_select");
@@ -28,8 +33,14 @@ pub const VOTE_COMMITTED: Symbol = symbol_short!("v_commit");
 pub const VOTE_REVEALED: Symbol = symbol_short!("v_reveal");
 pub const DISPUTE_RESOLVED: Symbol = symbol_short!("d_resolve");
 pub const DISPUTE_APPEALED: Symbol = symbol_short!("d_appeal");
+pub const DISPUTE_STATUS_CHANGED: Symbol = symbol_short!("d_status");
 pub const APPEAL_RESOLVED: Symbol = symbol_short!("a_resolve");
 pub const JUROR_SLASHED: Symbol = symbol_short!("j_slashed");
+pub const PANEL_DISSOLVED: Symbol = symbol_short!("j_dissolv");
+pub const SLASH_COMPENSATED: Symbol = symbol_short!("slash_cmp");
+pub const DELEGATE_SET: Symbol = symbol_short!("j_deleg");
+pub const JUROR_REWARDS_WITHDRAWN: Symbol = symbol_short!("j_rewards");
+pub const STAKE_REDUCED: Symbol = symbol_short!("stake_red");
 
 // Distribution events
 pub const PROFIT_DISTRIBUTED: Symbol = symbol_short!("profit");
@@ -44,6 +55,7 @@ pub const PROPOSAL_EXECUTED: Symbol = symbol_short!("execute");
 pub const USER_REGISTERED: Symbol = symbol_short!("user_reg");
 pub const REPUTATION_UPDATED: Symbol = symbol_short!("rep_up");
 pub const BADGE_EARNED: Symbol = symbol_short!("badge");
+pub const SCORE_CLAMPED: Symbol = symbol_short!("score_clp");
 
 // Multi-party payment events
 pub const PAYMENT_SETUP: Symbol = symbol_short!("pay_setup");
@@ -75,8 +87,12 @@ pub const BRIDGE_TX_CONFIRMED: Symbol = symbol_short!("tx_conf");
 pub const BRIDGE_TX_FAILED: Symbol = symbol_short!("tx_fail");
 pub const CONTRACT_PAUSED: Symbol = symbol_short!("esc_pause");
 pub const CONTRACT_RESUMED: Symbol = symbol_short!("esc_resum");
+pub const PROJECT_PAUSED: Symbol = symbol_short!("prj_pause");
+pub const PROJECT_RESUMED: Symbol = symbol_short!("prj_resum");
+pub const ADMIN_TRANSFERRED: Symbol = symbol_short!("adm_xfer");
 pub const EMERGENCY_WITHDRAW_APPROVED: Symbol = symbol_short!("emg_appr");
 pub const EMERGENCY_WITHDRAW_EXECUTED: Symbol = symbol_short!("emg_exec");
+pub const EMERGENCY_WITHDRAWAL: Symbol = symbol_short!("emg_wdraw");
 
 // Upgrade events
 pub const UPGRADE_SCHEDULED: Symbol = symbol_short!("upg_sched");
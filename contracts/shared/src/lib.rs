@@ -3,7 +3,9 @@
 pub mod constants;
 pub mod errors;
 pub mod events;
+pub mod math;
 pub mod types;
+pub mod upgradeable;
 pub mod utils;
 
 pub use constants::*;
@@ -0,0 +1,104 @@
+use soroban_sdk::Vec;
+
+/// Proportional part of `total`: `total * share / base`, rounded down
+///
+/// Rounding down means allocations computed against the same `base` never sum
+/// to more than `total`; hand out the leftover with `distribute_remainder`.
+///
+/// # Returns
+/// * `None` - On overflow or a non-positive `base`
+pub fn pro_rata(total: i128, share: i128, base: i128) -> Option<i128> {
+    if base <= 0 {
+        return None;
+    }
+    total.checked_mul(share)?.checked_div(base)
+}
+
+/// Hand out the units of `pool` not yet covered by `allocations`
+///
+/// The leftover is spread evenly, and the units that do not divide evenly go
+/// one each to the earliest allocations, so the result is deterministic for a
+/// given ordering and sums exactly to `pool`.
+///
+/// # Returns
+/// * `None` - If the allocations already exceed `pool`, or on overflow
+pub fn distribute_remainder(pool: i128, allocations: &mut Vec<i128>) -> Option<()> {
+    let mut allocated: i128 = 0;
+    for amount in allocations.iter() {
+        allocated = allocated.checked_add(amount)?;
+    }
+    let leftover = pool.checked_sub(allocated)?;
+    if leftover < 0 {
+        return None;
+    }
+    if leftover == 0 || allocations.is_empty() {
+        return Some(());
+    }
+
+    let count = allocations.len() as i128;
+    let per_entry = leftover / count;
+    let extra = (leftover % count) as u32;
+    for i in 0..allocations.len() {
+        let bonus = if i < extra { 1 } else { 0 };
+        let amount = allocations.get(i)? + per_entry + bonus;
+        allocations.set(i, amount);
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    #[test]
+    fn test_pro_rata_rounds_down() {
+        assert_eq!(pro_rata(100, 1, 3), Some(33));
+        assert_eq!(pro_rata(100, 2, 3), Some(66));
+        assert_eq!(pro_rata(1000, 2500, 10_000), Some(250));
+    }
+
+    #[test]
+    fn test_pro_rata_rejects_overflow_and_zero_base() {
+        assert_eq!(pro_rata(i128::MAX, 2, 3), None);
+        assert_eq!(pro_rata(100, 1, 0), None);
+    }
+
+    #[test]
+    fn test_distribute_remainder_sums_to_pool() {
+        let env = Env::default();
+        let pool = 100;
+        let mut allocations = Vec::new(&env);
+        for _ in 0..3 {
+            allocations.push_back(pro_rata(pool, 1, 3).unwrap());
+        }
+        assert_eq!(allocations.iter().sum::<i128>(), 99);
+
+        distribute_remainder(pool, &mut allocations).unwrap();
+        assert_eq!(allocations, vec![&env, 34, 33, 33]);
+    }
+
+    #[test]
+    fn test_distribute_remainder_never_exceeds_pool() {
+        let env = Env::default();
+        let weights = [7i128, 11, 13, 17, 19];
+        let base: i128 = weights.iter().sum();
+        for pool in [0i128, 1, 4, 66, 999, 1_000_003] {
+            let mut allocations = Vec::new(&env);
+            for weight in weights {
+                allocations.push_back(pro_rata(pool, weight, base).unwrap());
+            }
+            assert!(allocations.iter().sum::<i128>() <= pool);
+
+            distribute_remainder(pool, &mut allocations).unwrap();
+            assert_eq!(allocations.iter().sum::<i128>(), pool);
+        }
+    }
+
+    #[test]
+    fn test_distribute_remainder_rejects_over_allocation() {
+        let env = Env::default();
+        let mut allocations = vec![&env, 60, 50];
+        assert_eq!(distribute_remainder(100, &mut allocations), None);
+    }
+}
@@ -42,6 +42,41 @@ pub struct UserProfile {
     pub verified: bool,
 }
 
+// ==================== Reputation Types ====================
+
+/// Badge types that can be awarded to users
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum BadgeType {
+    /// Awarded for making contributions to projects
+    Contributor = 0,
+    /// Awarded to verified creators
+    VerifiedCreator = 1,
+    /// Awarded to top investors by volume
+    TopInvestor = 2,
+    /// Awarded for early platform adoption
+    EarlyAdopter = 3,
+    /// Awarded for completing first project
+    FirstProject = 4,
+    /// Awarded for successful milestone completion
+    MilestoneAchiever = 5,
+    /// Awarded for governance participation
+    GovernanceParticipant = 6,
+}
+
+/// User reputation profile
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReputationProfile {
+    /// User's address
+    pub user: Address,
+    /// Reputation score (clamped between REPUTATION_MIN and REPUTATION_MAX)
+    pub score: i128,
+    /// List of badges earned by the user
+    pub badges: Vec<BadgeType>,
+}
+
 /// Regulatory Jurisdiction
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -64,6 +99,12 @@ pub struct EscrowInfo {
     pub validators: Vec<Address>,
     pub approval_threshold: u32,
     pub management_fee_bps: u32,
+    /// Last timestamp at which deposits are accepted, 0 for no deadline
+    pub deposit_deadline: u64,
+    /// Validator votes a milestone needs before its vote can finalize, 0 for none
+    pub quorum: u32,
+    /// Contribution a backer other than the creator needs to open a dispute
+    pub min_dispute_contribution: Amount,
 }
 
 #[contracttype]
@@ -73,6 +114,16 @@ pub enum MilestoneStatus {
     Submitted = 1, // Submitted with proof, awaiting validator votes
     Approved = 2,  // Approved by majority, funds released
     Rejected = 3,  // Rejected by majority
+    Cancelled = 4, // Abandoned by the creator, unreleased amount freed
+}
+
+/// Immediate effect of a validator's milestone vote
+#[contracttype]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VoteOutcome {
+    Recorded = 0, // Vote counted, milestone still awaiting votes
+    Approved = 1, // Vote met the approval threshold, funds released
+    Rejected = 2, // Vote made rejection final
 }
 
 /// Milestone structure
@@ -83,11 +134,26 @@ pub struct Milestone {
     pub project_id: u64,
     pub description_hash: Hash,
     pub amount: Amount,
+    /// Paid out so far across partial and full releases; never exceeds `amount`
+    pub released_so_far: Amount,
+    /// Asset the milestone is paid in; the escrow's `token` unless overridden
+    pub token: Address,
     pub status: MilestoneStatus,
     pub proof_hash: Hash,
+    /// Off-chain location of the proof (e.g. IPFS/HTTPS); `proof_hash` guards its integrity
+    pub proof_uri: String,
     pub approval_count: u32,
     pub rejection_count: u32,
+    /// Times the milestone was put back up for a vote after being rejected
+    pub resubmission_count: u32,
     pub created_at: Timestamp,
+    /// When the proof was last submitted, 0 while pending; opens the contest window
+    pub submitted_at: Timestamp,
+    /// End of the current vote round, 0 while pending; see `finalize_milestone_vote`
+    pub vote_deadline: Timestamp,
+    /// When the validators' vote last rejected the milestone, 0 if it never did;
+    /// reopens the contest window
+    pub rejected_at: Timestamp,
 }
 
 // ==================== Dispute Resolution Types ====================
@@ -112,6 +178,8 @@ pub enum DisputeResolution {
     RelFunds = 1,
     RefBackers = 2,
     PartRel = 3,
+    /// A juror's explicit abstention; counts as participation but never wins
+    Abstain = 4,
 }
 
 /// Dispute structure
@@ -127,6 +195,11 @@ pub struct Dispute {
     pub resolution: DisputeResolution,
     pub resolution_payload: u32,
     pub appeal_count: u32,
+    /// Who filed each appeal, in order
+    pub appellants: Vec<Address>,
+    /// Winning side's share of slashed stake, held out of the fee pool until
+    /// `execute_resolution` pays it to the final winner
+    pub pending_compensation: Amount,
 }
 
 /// Juror Information
@@ -135,9 +208,46 @@ pub struct Dispute {
 pub struct JurorInfo {
     pub address: Address,
     pub staked_amount: Amount,
+    /// Fee-pool rewards earned but not yet withdrawn; never slashed
+    pub rewards_accrued: Amount,
     pub active_disputes: u32,
     pub successful_votes: u32,
     pub missed_votes: u32,
+    /// Stake lost to slashing over the juror's lifetime
+    pub total_slashed: Amount,
+}
+
+/// Economic parameters of the dispute system, set per deployment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeConfig {
+    pub min_juror_stake: Amount,
+    /// Jurors seated on a first-round panel (odd)
+    pub jury_size: u32,
+    /// Jurors seated on an appeal panel (odd and larger than `jury_size`)
+    pub appeal_jury_size: u32,
+    /// Penalty per minority or missing vote, in basis points of the juror's stake
+    pub slash_bps: u32,
+    /// Share of a tally's slashed stake, in basis points, paid to the side the
+    /// panel found for; the rest joins the juror reward pool
+    pub slash_to_winner_bps: u32,
+    /// Fee for the first appeal; each later appeal on a dispute adds one more
+    pub appeal_fee: Amount,
+    /// Bond locked from the juror's stake on each commit; returned to the
+    /// majority and forfeited to the fee pool otherwise
+    pub juror_bond: Amount,
+}
+
+/// Aggregate health of the jury system
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeStats {
+    pub active_jurors: u32,
+    /// Sum of every registered juror's stake, excluding bonds locked in open disputes
+    pub total_staked: Amount,
+    /// Fee pool held in the global dispute token
+    pub fee_pool: Amount,
+    pub next_dispute_id: u64,
 }
 
 /// Vote commitment for the commit-reveal scheme
@@ -148,6 +258,8 @@ pub struct VoteCommitment {
     pub revealed: bool,
     pub vote: DisputeResolution,
     pub vote_payload: u32,
+    /// Revealed during the grace period: counted, but not eligible for rewards
+    pub late: bool,
 }
 
 /// Proposal status
@@ -306,6 +418,16 @@ pub struct PendingUpgrade {
     pub execute_not_before: u64,
 }
 
+/// Outcome of a non-atomic batch call
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchResult {
+    /// Number of items applied
+    pub succeeded: u32,
+    /// Identifiers of the items that were skipped
+    pub failed: Vec<u64>,
+}
+
 // ==================== Oracle Types ====================
 
 #[contracttype]
@@ -0,0 +1,166 @@
+use soroban_sdk::{Address, Env};
+
+use crate::constants::UPGRADE_TIME_LOCK_SECS;
+use crate::errors::Error;
+use crate::events::{UPGRADE_CANCELLED, UPGRADE_EXECUTED, UPGRADE_SCHEDULED};
+use crate::types::{Hash, PendingUpgrade};
+
+/// Storage hooks a contract provides to reuse the time-locked upgrade flow.
+///
+/// Each contract keeps its own storage layout; the functions in this module
+/// only enforce the admin, pause and time-lock rules on top of these hooks.
+pub trait Upgradeable {
+    /// Address allowed to schedule, execute and cancel upgrades
+    fn upgrade_admin(env: &Env) -> Result<Address, Error>;
+
+    /// Whether the contract is currently paused
+    fn is_paused(env: &Env) -> bool;
+
+    /// The scheduled upgrade, if any
+    fn pending_upgrade(env: &Env) -> Option<PendingUpgrade>;
+
+    /// Store a scheduled upgrade
+    fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade);
+
+    /// Remove the scheduled upgrade
+    fn clear_pending_upgrade(env: &Env);
+
+    /// Current contract version (starts at 1)
+    fn version(env: &Env) -> u32;
+
+    /// Store the contract version
+    fn set_version(env: &Env, version: u32);
+}
+
+/// Schedule an upgrade, executable after UPGRADE_TIME_LOCK_SECS
+pub fn schedule_upgrade<T: Upgradeable>(
+    env: &Env,
+    admin: &Address,
+    new_wasm_hash: Hash,
+) -> Result<PendingUpgrade, Error> {
+    require_upgrade_admin::<T>(env, admin)?;
+
+    let pending = PendingUpgrade {
+        wasm_hash: new_wasm_hash.clone(),
+        execute_not_before: env.ledger().timestamp() + UPGRADE_TIME_LOCK_SECS,
+    };
+    T::set_pending_upgrade(env, &pending);
+    env.events().publish(
+        (UPGRADE_SCHEDULED,),
+        (admin.clone(), new_wasm_hash, pending.execute_not_before),
+    );
+    Ok(pending)
+}
+
+/// Execute the scheduled upgrade and bump the contract version
+///
+/// The contract must be paused and the time-lock must have elapsed.
+pub fn execute_upgrade<T: Upgradeable>(env: &Env, admin: &Address) -> Result<u32, Error> {
+    require_upgrade_admin::<T>(env, admin)?;
+
+    let pending = T::pending_upgrade(env);
+    check_upgrade_ready(
+        T::is_paused(env),
+        pending.as_ref().map(|p| p.execute_not_before),
+        env.ledger().timestamp(),
+    )?;
+    let pending = pending.ok_or(Error::UpgNotSched)?;
+
+    env.deployer()
+        .update_current_contract_wasm(pending.wasm_hash.clone());
+
+    let version = T::version(env) + 1;
+    T::set_version(env, version);
+    T::clear_pending_upgrade(env);
+    env.events()
+        .publish((UPGRADE_EXECUTED,), (admin.clone(), pending.wasm_hash));
+    Ok(version)
+}
+
+/// Cancel the scheduled upgrade
+pub fn cancel_upgrade<T: Upgradeable>(env: &Env, admin: &Address) -> Result<(), Error> {
+    require_upgrade_admin::<T>(env, admin)?;
+
+    if T::pending_upgrade(env).is_none() {
+        return Err(Error::UpgNotSched);
+    }
+    T::clear_pending_upgrade(env);
+    env.events().publish((UPGRADE_CANCELLED,), admin.clone());
+    Ok(())
+}
+
+/// Drop any upgrade scheduled by an outgoing admin so the new admin must re-schedule
+///
+/// Call from the contract's admin-transfer path. Returns true if an upgrade was cancelled.
+pub fn invalidate_on_admin_change<T: Upgradeable>(env: &Env, old_admin: &Address) -> bool {
+    if T::pending_upgrade(env).is_none() {
+        return false;
+    }
+    T::clear_pending_upgrade(env);
+    env.events()
+        .publish((UPGRADE_CANCELLED,), old_admin.clone());
+    true
+}
+
+/// Check the pause and time-lock invariants for executing an upgrade
+///
+/// # Arguments
+/// * `paused` - Whether the contract is paused
+/// * `execute_not_before` - Time-lock of the pending upgrade, None if nothing is scheduled
+/// * `now` - Current ledger timestamp
+pub fn check_upgrade_ready(
+    paused: bool,
+    execute_not_before: Option<u64>,
+    now: u64,
+) -> Result<(), Error> {
+    if !paused {
+        return Err(Error::UpgReqPause);
+    }
+    let execute_not_before = execute_not_before.ok_or(Error::UpgNotSched)?;
+    if now < execute_not_before {
+        return Err(Error::UpgTooEarly);
+    }
+    Ok(())
+}
+
+fn require_upgrade_admin<T: Upgradeable>(env: &Env, admin: &Address) -> Result<(), Error> {
+    let stored_admin = T::upgrade_admin(env)?;
+    if stored_admin != *admin {
+        return Err(Error::Unauthorized);
+    }
+    admin.require_auth();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_requires_pause() {
+        assert_eq!(
+            check_upgrade_ready(false, Some(100), 200),
+            Err(Error::UpgReqPause)
+        );
+    }
+
+    #[test]
+    fn test_upgrade_requires_schedule() {
+        assert_eq!(
+            check_upgrade_ready(true, None, 200),
+            Err(Error::UpgNotSched)
+        );
+    }
+
+    #[test]
+    fn test_upgrade_respects_time_lock() {
+        let scheduled_at = 1_000;
+        let unlock = scheduled_at + UPGRADE_TIME_LOCK_SECS;
+
+        assert_eq!(
+            check_upgrade_ready(true, Some(unlock), unlock - 1),
+            Err(Error::UpgTooEarly)
+        );
+        assert_eq!(check_upgrade_ready(true, Some(unlock), unlock), Ok(()));
+    }
+}